@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::macros::*;
+use crate::BoxedError;
+
+/// Answers in `answers.toml` can be written either as strings or as plain numbers
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Text(String),
+    Number(i64),
+}
+
+impl Answer {
+    fn into_string(self) -> String {
+        match self {
+            Answer::Text(text) => text,
+            Answer::Number(num) => num.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawExpectedAnswers {
+    part_1: Option<Answer>,
+    part_2: Option<Answer>,
+}
+
+/// Loads an answers file with `parse`, or an empty one if it doesn't exist
+fn load_answers<T: Default>(
+    path: &Path,
+    parse: fn(&str) -> Result<T, BoxedError>,
+) -> Result<T, BoxedError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(unwrap_or_return!(
+            parse(&contents),
+            error: e,
+            "Failed to load answers (Path: \"{}\")\nReason: {}",
+            path.display(),
+            e
+        )),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => return_err!(
+            io: "Failed to open answers (Path: \"{}\")\nReason: {}",
+            path.display(),
+            e
+        ),
+    }
+}
+
+/// The known-correct answers for a single day
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl From<RawExpectedAnswers> for ExpectedAnswers {
+    fn from(answers: RawExpectedAnswers) -> Self {
+        Self {
+            part_1: answers.part_1.map(Answer::into_string),
+            part_2: answers.part_2.map(Answer::into_string),
+        }
+    }
+}
+
+impl ExpectedAnswers {
+    /// Parses the answers for a single input, such as an example's answers:
+    ///
+    /// ```toml
+    /// part_1 = 13
+    /// part_2 = 1
+    /// ```
+    pub fn parse(contents: &str) -> Result<Self, BoxedError> {
+        let raw: RawExpectedAnswers = unwrap_or_return!(
+            toml::from_str(contents),
+            error: e,
+            "Invalid answers file!\nReason: {}",
+            e
+        );
+
+        Ok(raw.into())
+    }
+
+    /// Loads the answers at `path`. A missing file is treated as having no known answers
+    pub fn load(path: &Path) -> Result<Self, BoxedError> {
+        load_answers(path, Self::parse)
+    }
+}
+
+/// The known-correct answers for every day in a year, as stored in `answers.toml`:
+///
+/// ```toml
+/// [day_1]
+/// part_1 = 24000
+/// part_2 = "45000"
+/// ```
+#[derive(Default, Debug)]
+pub struct AnswerStore {
+    days: HashMap<String, ExpectedAnswers>,
+}
+
+impl AnswerStore {
+    pub fn parse(contents: &str) -> Result<Self, BoxedError> {
+        let raw: HashMap<String, RawExpectedAnswers> = unwrap_or_return!(
+            toml::from_str(contents),
+            error: e,
+            "Invalid answers file!\nReason: {}",
+            e
+        );
+
+        let days = raw
+            .into_iter()
+            .map(|(day, answers)| (day, answers.into()))
+            .collect();
+
+        Ok(Self { days })
+    }
+
+    /// Loads the answers at `path`. A missing file is treated as having no known answers
+    pub fn load(path: &Path) -> Result<Self, BoxedError> {
+        load_answers(path, Self::parse)
+    }
+
+    pub fn get(&self, day_num: usize) -> ExpectedAnswers {
+        self.days
+            .get(&format!("day_{day_num}"))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    /// Surrounding whitespace is ignored, since multi-line answers are awkward to store exactly
+    pub fn check(expected: Option<&str>, actual: &str) -> Self {
+        match expected {
+            Some(expected) if expected.trim() == actual.trim() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.trim().to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "✔",
+            Verdict::Wrong { .. } => "✘",
+            Verdict::Unknown => "?",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Wrong { expected } => write!(f, "{} (Expected {expected})", self.symbol()),
+            _ => f.write_str(self.symbol()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnswerStore, ExpectedAnswers, Verdict};
+
+    #[test]
+    fn parse_store() {
+        let store = AnswerStore::parse(
+            r#"[day_1]
+part_1 = 24000
+part_2 = "45000"
+
+[day_9]
+part_1 = "13""#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            ExpectedAnswers {
+                part_1: Some("24000".to_string()),
+                part_2: Some("45000".to_string()),
+            },
+            store.get(1)
+        );
+
+        assert_eq!(None, store.get(9).part_2);
+        assert_eq!(ExpectedAnswers::default(), store.get(2));
+
+        assert!(AnswerStore::parse("[day_1]\npart_1 = [1, 2]").is_err());
+    }
+
+    #[test]
+    fn parse_expected() {
+        assert_eq!(
+            ExpectedAnswers {
+                part_1: Some("13".to_string()),
+                part_2: Some("1".to_string()),
+            },
+            ExpectedAnswers::parse("part_1 = 13\npart_2 = \"1\"").unwrap()
+        );
+
+        assert_eq!(
+            ExpectedAnswers::default(),
+            ExpectedAnswers::parse("").unwrap()
+        );
+        assert!(ExpectedAnswers::parse("part_1 = [1, 2]").is_err());
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(Verdict::Correct, Verdict::check(Some("13"), "13"));
+        assert_eq!(Verdict::Correct, Verdict::check(Some("\n##\n"), "\n\n##"));
+        assert_eq!(
+            Verdict::Wrong {
+                expected: "13".to_string()
+            },
+            Verdict::check(Some("13"), "14")
+        );
+        assert_eq!(Verdict::Unknown, Verdict::check(None, "13"));
+    }
+}
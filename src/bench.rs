@@ -0,0 +1,296 @@
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::error::AocError;
+use crate::isolate::catch_panic;
+use crate::macros::*;
+use crate::report::{format_millis, print_rows};
+use crate::BoxedError;
+use crate::Day;
+use crate::Timings;
+use crate::Year;
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Stats {
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+    pub p95_ns: u128,
+    pub std_dev_ns: u128,
+}
+
+impl Stats {
+    /// Returns `None` if there aren't any samples
+    pub fn new(samples: &[u128]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let len = sorted.len();
+        let median = if len % 2 == 0 {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+
+        // Nearest-rank percentile, so p95 is always one of the samples
+        let p95 = sorted[(len * 95).div_ceil(100) - 1];
+
+        let mean = sorted.iter().sum::<u128>() as f64 / len as f64;
+        let variance = sorted
+            .iter()
+            .map(|sample| (*sample as f64 - mean).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Some(Self {
+            min_ns: sorted[0],
+            median_ns: median,
+            mean_ns: mean.round() as u128,
+            p95_ns: p95,
+            std_dev_ns: variance.sqrt().round() as u128,
+        })
+    }
+
+    fn get_row(&self, phase: &str) -> [String; 6] {
+        [
+            phase.to_string(),
+            format_millis(self.min_ns),
+            format_millis(self.median_ns),
+            format_millis(self.mean_ns),
+            format_millis(self.p95_ns),
+            format_millis(self.std_dev_ns),
+        ]
+    }
+}
+
+#[derive(Serialize, Clone, Copy, Debug)]
+pub struct PhaseStats {
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+    pub total: Stats,
+}
+
+impl PhaseStats {
+    fn new(timings: &[Timings]) -> Option<Self> {
+        let get_stats = |phase: fn(&Timings) -> u128| {
+            Stats::new(&timings.iter().map(phase).collect::<Vec<u128>>())
+        };
+
+        Some(Self {
+            parse: get_stats(|timings| timings.parse)?,
+            part_1: get_stats(|timings| timings.part_1)?,
+            part_2: get_stats(|timings| timings.part_2)?,
+            total: get_stats(Timings::total)?,
+        })
+    }
+}
+
+pub enum BenchStatus {
+    Completed(Box<PhaseStats>),
+    Skipped(String),
+    Failed(String),
+}
+
+pub struct BenchReport {
+    pub year: u32,
+    pub day: usize,
+    pub name: String,
+    pub runs: usize,
+    pub warmup: usize,
+    pub status: BenchStatus,
+}
+
+/// A single benchmark, as it's written when using `--format json`
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u32,
+    day: usize,
+    name: &'a str,
+    status: &'a str,
+    runs: usize,
+    warmup: usize,
+    stats: Option<&'a PhaseStats>,
+    /// Why the day was skipped
+    reason: Option<&'a str>,
+    error: Option<&'a str>,
+}
+
+impl BenchReport {
+    pub fn print(&self) {
+        println!(
+            "Year {}, Day {}: {} ({} Runs After {} Warm-Up Runs)",
+            self.year, self.day, self.name, self.runs, self.warmup
+        );
+
+        match &self.status {
+            BenchStatus::Completed(stats) => print_rows(
+                &["Phase", "Min", "Median", "Mean", "p95", "Std Dev"],
+                &[
+                    stats.parse.get_row("Parse"),
+                    stats.part_1.get_row("Part 1"),
+                    stats.part_2.get_row("Part 2"),
+                    stats.total.get_row("Total"),
+                ],
+            ),
+            BenchStatus::Skipped(reason) => println!("Skipped: {reason}"),
+            BenchStatus::Failed(error) => eprintln!("An error occured: {error}"),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let (status, stats, reason, error) = match &self.status {
+            BenchStatus::Completed(stats) => ("completed", Some(stats.as_ref()), None, None),
+            BenchStatus::Skipped(reason) => ("skipped", None, Some(reason.as_str()), None),
+            BenchStatus::Failed(error) => ("failed", None, None, Some(error.as_str())),
+        };
+
+        let record = Record {
+            year: self.year,
+            day: self.day,
+            name: &self.name,
+            status,
+            runs: self.runs,
+            warmup: self.warmup,
+            stats,
+            reason,
+            error,
+        };
+
+        serde_json::to_string(&record).unwrap()
+    }
+}
+
+/// Runs the day once, failing if either part fails. A part that isn't complete yet is still measured, since
+/// it returns straight away. The day runs on this thread rather than through `Day::execute`, so that starting
+/// a thread for every run isn't measured along with it (which also means there's no time limit)
+fn time_day(day: &Day, input: &str) -> Result<Timings, BoxedError> {
+    let answers = catch_panic(|| (day.function)(input))?;
+
+    if answers.is_not_implemented() {
+        return_err!(not_implemented);
+    }
+
+    if let Some(error) = answers.get_error() {
+        return Err(Box::new(error.clone()));
+    }
+
+    Ok(answers.timings)
+}
+
+fn run_benchmark(
+    day: &Day,
+    input: &str,
+    runs: usize,
+    warmup: usize,
+) -> Result<PhaseStats, BoxedError> {
+    for _i in 0..warmup {
+        time_day(day, input)?;
+    }
+
+    let mut timings = Vec::with_capacity(runs);
+
+    for _i in 0..runs {
+        timings.push(time_day(day, input)?);
+    }
+
+    Ok(unwrap_option_or_return!(
+        PhaseStats::new(&timings),
+        "A benchmark needs at least 1 run!"
+    ))
+}
+
+fn create_report(
+    year: &Year,
+    day: &Day,
+    status: BenchStatus,
+    runs: usize,
+    warmup: usize,
+) -> BenchReport {
+    BenchReport {
+        year: year.year,
+        day: day.day,
+        name: day.name.to_string(),
+        runs,
+        warmup,
+        status,
+    }
+}
+
+/// Runs the day `warmup` times without measuring it, and then another `runs` times.
+/// The input is only read once by the caller, so only the day itself is measured
+pub(crate) fn bench_day(
+    year: &Year,
+    day: &Day,
+    input: &str,
+    runs: usize,
+    warmup: usize,
+) -> BenchReport {
+    let input = day.prepare_input(input).input;
+
+    let status = match run_benchmark(day, &input, runs, warmup) {
+        Ok(stats) => BenchStatus::Completed(Box::new(stats)),
+        Err(e) => match AocError::from(e) {
+            AocError::NotImplemented => BenchStatus::Skipped("Not Complete".to_string()),
+            error => BenchStatus::Failed(error.to_string()),
+        },
+    };
+
+    create_report(year, day, status, runs, warmup)
+}
+
+/// Benchmarks every day in the year that has an input, without creating any input files
+pub(crate) fn bench_year(
+    year: &Year,
+    runs: usize,
+    warmup: usize,
+    config: &Config,
+) -> Vec<BenchReport> {
+    let mut reports = Vec::new();
+
+    for day in year.days.iter() {
+        let report = match year.read_input(day.day, config) {
+            Ok(Some(input)) => bench_day(year, day, &input, runs, warmup),
+            Ok(None) => create_report(
+                year,
+                day,
+                BenchStatus::Skipped("No Input".to_string()),
+                runs,
+                warmup,
+            ),
+            Err(e) => create_report(year, day, BenchStatus::Failed(e.to_string()), runs, warmup),
+        };
+
+        reports.push(report);
+    }
+
+    reports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new(&[5, 1, 4, 2, 3]).unwrap();
+
+        assert_eq!(1, stats.min_ns);
+        assert_eq!(3, stats.median_ns);
+        assert_eq!(3, stats.mean_ns);
+        assert_eq!(5, stats.p95_ns);
+        assert_eq!(1, stats.std_dev_ns);
+
+        let stats = Stats::new(&(1..=100).collect::<Vec<u128>>()).unwrap();
+
+        assert_eq!(50, stats.median_ns);
+        assert_eq!(95, stats.p95_ns);
+        assert_eq!(29, stats.std_dev_ns);
+
+        assert_eq!(None, Stats::new(&[]));
+    }
+}
@@ -0,0 +1,132 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Puzzles unlock at midnight in UTC-5, so that's the timezone the puzzle dates are in
+const UNLOCK_OFFSET_SECONDS: i64 = -5 * 60 * 60;
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// The last day of an event. There were 25 days each year until 2025, when it went down to 12
+pub fn get_last_day(year: u32) -> usize {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// The current time, in seconds since the Unix epoch
+pub(crate) fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default()
+}
+
+/// The date (year, month, day) in the unlock timezone at the given Unix time
+pub fn get_unlock_date(unix_time: u64) -> (u32, u32, u32) {
+    let days = (unix_time as i64 + UNLOCK_OFFSET_SECONDS).div_euclid(SECONDS_PER_DAY);
+
+    // Converts the days since 1970-01-01 to a date in the proleptic Gregorian calendar, with the years
+    // starting in March so that leap days fall at the end of them
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year as u32, month as u32, day as u32)
+}
+
+/// The year and day of the latest puzzle, if there's an event on at the given Unix time
+pub fn get_current_puzzle(unix_time: u64) -> Option<(u32, usize)> {
+    match get_unlock_date(unix_time) {
+        (year, 12, day) if day as usize <= get_last_day(year) => Some((year, day as usize)),
+        _ => None,
+    }
+}
+
+/// The year and day of the puzzle that unlocks at the next midnight, along with how many seconds away that is.
+/// Returns `None` if no puzzle unlocks at the next midnight
+pub fn get_next_unlock(unix_time: u64) -> Option<(u32, usize, u64)> {
+    let local_time = unix_time as i64 + UNLOCK_OFFSET_SECONDS;
+    let seconds_left = (SECONDS_PER_DAY - local_time.rem_euclid(SECONDS_PER_DAY)) as u64;
+    let (year, day) = get_current_puzzle(unix_time + seconds_left)?;
+
+    Some((year, day, seconds_left))
+}
+
+pub fn format_countdown(seconds: u64) -> String {
+    format!(
+        "{}h {}m {}s",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    /// 2022-12-01 00:00:00 in UTC
+    const DECEMBER_2022: u64 = 1_669_852_800;
+    const HOUR: u64 = 60 * 60;
+    const DAY: u64 = 24 * HOUR;
+
+    #[test]
+    fn unlock_dates() {
+        assert_eq!((1970, 1, 1), super::get_unlock_date(5 * HOUR));
+        assert_eq!((1969, 12, 31), super::get_unlock_date(0));
+        assert_eq!((2024, 2, 29), super::get_unlock_date(1_709_182_800));
+
+        // Midnight in UTC is still the day before in UTC-5
+        assert_eq!((2022, 11, 30), super::get_unlock_date(DECEMBER_2022));
+        assert_eq!(
+            (2022, 12, 1),
+            super::get_unlock_date(DECEMBER_2022 + 5 * HOUR)
+        );
+    }
+
+    #[test]
+    fn current_puzzle() {
+        let unlock = DECEMBER_2022 + 5 * HOUR;
+
+        assert_eq!(None, super::get_current_puzzle(unlock - 1));
+        assert_eq!(Some((2022, 1)), super::get_current_puzzle(unlock));
+        assert_eq!(
+            Some((2022, 25)),
+            super::get_current_puzzle(unlock + 25 * DAY - 1)
+        );
+        assert_eq!(None, super::get_current_puzzle(unlock + 25 * DAY));
+
+        assert_eq!(
+            Some((2022, 1, 2 * HOUR)),
+            super::get_next_unlock(unlock - 2 * HOUR)
+        );
+        assert_eq!(
+            Some((2022, 15, 1)),
+            super::get_next_unlock(unlock + 14 * DAY - 1)
+        );
+        assert_eq!(None, super::get_next_unlock(unlock + 24 * DAY));
+        assert_eq!(None, super::get_next_unlock(unlock - DAY - 1));
+
+        assert_eq!("3h 2m 1s", super::format_countdown(3 * HOUR + 121));
+
+        // 2025-12-01 00:00:00 in UTC-5, after which there are only 12 days
+        let unlock = 1_764_565_200;
+
+        assert_eq!(
+            Some((2025, 12)),
+            super::get_current_puzzle(unlock + 12 * DAY - 1)
+        );
+        assert_eq!(None, super::get_current_puzzle(unlock + 12 * DAY));
+        assert_eq!(None, super::get_next_unlock(unlock + 11 * DAY));
+    }
+}
@@ -0,0 +1,929 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use clearscreen::clear;
+
+use crate::answers::Verdict;
+use crate::bench::{bench_day, bench_year, BenchStatus};
+use crate::calendar;
+use crate::client::Client;
+use crate::config::{Config, ConfigArgs};
+use crate::describe::{describe_day, load_page};
+use crate::fetch::fetch_input;
+use crate::macros::*;
+use crate::report::{print_comparison, print_summary, print_table, DayReport, Status};
+use crate::scaffold::{create_day, find_crate_root};
+use crate::submit::{Outcome, Response, SubmissionLog};
+use crate::watch::{print_watch_table, Watcher};
+use crate::BoxedError;
+use crate::Day;
+use crate::Year;
+use crate::STDIN_INPUT;
+use crate::TODAY;
+
+/// Every day that ran produced both of its answers
+pub const EXIT_SUCCESS: u8 = 0;
+/// A day was found, but failed to produce its answers
+pub const EXIT_FAILURE: u8 = 1;
+/// The requested year or day couldn't be found
+pub const EXIT_USAGE: u8 = 2;
+/// Ctrl-C was pressed while no day was running
+pub const EXIT_INTERRUPTED: u8 = 130;
+
+/// Bobby Shmurner's Advent Of Code solutions.
+///
+/// Run without a subcommand to open the interactive menu.
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// How the results are printed
+    #[arg(short, long, value_enum, global = true, default_value_t = Format::Text)]
+    pub format: Format,
+
+    #[command(flatten)]
+    pub config: ConfigArgs,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable answers and tables
+    Text,
+    /// One JSON record per line, per day that was run
+    Json,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run a single day and print its answers
+    Run(RunArgs),
+    /// Run every day of every year and print a summary table
+    All,
+    /// Run a day many times and print statistics about how long each phase took
+    Bench(BenchArgs),
+    /// Check answers against the known answers in each year's answers.toml.
+    /// Verifies every day if no day is given
+    Verify(VerifyArgs),
+    /// Run a day against its input and every one of its named inputs (e.g. "day_9/alice.txt"),
+    /// and print their answers side by side
+    Compare(CompareArgs),
+    /// Keep running a day against its input and examples, rerunning it whenever any of them change
+    Watch(WatchArgs),
+    /// Download a day's input using the session token. Inputs that were already downloaded are never
+    /// downloaded again
+    Fetch(FetchArgs),
+    /// Run a day and submit one of its answers. Answers that are known to be wrong from earlier
+    /// submissions aren't submitted
+    Submit(SubmitArgs),
+    /// Save a day's puzzle description as Markdown, and its examples as example inputs
+    Describe(DescribeArgs),
+    /// Create a new day from the day template, along with its input and example files
+    New(NewArgs),
+}
+
+impl Command {
+    pub fn execute(self, format: Format, config: &Config) -> ExitCode {
+        match self {
+            Command::Run(args) => args.execute(format, config),
+            Command::All => run_all(format, config),
+            Command::Bench(args) => args.execute(format, config),
+            Command::Verify(args) => args.execute(format, config),
+            Command::Compare(args) => args.execute(format, config),
+            Command::Watch(args) => args.execute(format, config),
+            Command::Fetch(args) => args.execute(format, config),
+            Command::Submit(args) => args.execute(format, config),
+            Command::Describe(args) => args.execute(config),
+            Command::New(args) => args.execute(config),
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    /// Read the input from this file instead of the day's usual input.
+    /// Use "-" (or put "-" after the day) to read it from stdin
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<String>,
+
+    /// Run the day against one of its examples (e.g. "day_9.example.txt") instead of its input,
+    /// checking the answers against the example's known answers in "day_9.example.toml".
+    /// Give a number to use a later example, such as "--example=2" for "day_9.example_2.txt"
+    #[arg(
+        short,
+        long,
+        value_name = "NUMBER",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "1",
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with = "input"
+    )]
+    example: Option<u32>,
+
+    /// Run the day against one of its named inputs, such as a teammate's input in "day_9/alice.txt"
+    #[arg(short, long, conflicts_with_all = ["input", "example"])]
+    name: Option<String>,
+}
+
+#[derive(Args)]
+pub struct TargetArgs {
+    /// The day to run, either as "YEAR/DAY" (e.g. "2022/9") or as "YEAR DAY".
+    /// "today" picks today's puzzle during December, or the latest day otherwise
+    #[arg(value_name = "TARGET", num_args = 0..=3)]
+    target: Vec<String>,
+
+    /// The year to run
+    #[arg(short, long)]
+    year: Option<String>,
+
+    /// The day to run
+    #[arg(short, long)]
+    day: Option<String>,
+}
+
+impl TargetArgs {
+    fn is_empty(&self) -> bool {
+        self.target.is_empty() && self.year.is_none() && self.day.is_none()
+    }
+
+    fn is_today(&self) -> bool {
+        self.target
+            .iter()
+            .chain(&self.year)
+            .chain(&self.day)
+            .any(|target| target.eq_ignore_ascii_case(TODAY))
+    }
+
+    /// Removes a trailing "-" from the target, returning whether there was one
+    fn take_stdin(&mut self) -> bool {
+        if self.target.len() > 1 && self.target.last().is_some_and(|arg| arg == STDIN_INPUT) {
+            self.target.pop();
+            return true;
+        }
+
+        false
+    }
+
+    fn get_target(&self) -> Result<(String, String), BoxedError> {
+        let (mut year, mut day) = match self.target.as_slice() {
+            [] => (None, None),
+            [target] if target.eq_ignore_ascii_case(TODAY) => {
+                (Some(target.clone()), Some(target.clone()))
+            }
+            [target] => match target.split_once('/') {
+                Some((year, day)) => (Some(year.to_string()), Some(day.to_string())),
+                None => (Some(target.clone()), None),
+            },
+            [year, day] => (Some(year.clone()), Some(day.clone())),
+            [_, _, extra, ..] => return_err!("Unexpected value \"{}\"!", extra),
+        };
+
+        if self.year.is_some() {
+            if year.is_some() {
+                return_err!("The year was specified more than once!");
+            }
+
+            year = self.year.clone();
+        }
+
+        if self.day.is_some() {
+            if day.is_some() {
+                return_err!("The day was specified more than once!");
+            }
+
+            day = self.day.clone();
+        }
+
+        let year = unwrap_option_or_return!(year, "No year was specified!");
+        let day = unwrap_option_or_return!(day, "No day was specified!");
+
+        Ok((year, day))
+    }
+
+    fn select<'a>(&self, years: &'a [Year]) -> Result<(&'a Year, &'a Day), BoxedError> {
+        let (year, day) = self.get_target()?;
+
+        let year = Year::get_year(years, year.trim())?;
+        let day = year.get_day(day.trim())?;
+
+        Ok((year, day))
+    }
+}
+
+impl RunArgs {
+    fn get_input_path(&mut self) -> Result<Option<String>, BoxedError> {
+        match (self.input.take(), self.target.take_stdin()) {
+            (_, true) if self.example.is_some() || self.name.is_some() => {
+                return_err!("The input was specified more than once!")
+            }
+            (Some(_), true) => return_err!("The input was specified more than once!"),
+            (Some(path), false) => Ok(Some(path)),
+            (None, true) => Ok(Some(STDIN_INPUT.to_string())),
+            (None, false) => Ok(None),
+        }
+    }
+
+    fn execute(mut self, format: Format, config: &Config) -> ExitCode {
+        let years = Year::create_years();
+
+        let input_path = unwrap_or_else!(self.get_input_path(), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_USAGE);
+        });
+
+        let (year, day) = unwrap_or_else!(self.target.select(&years), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_USAGE);
+        });
+
+        if let Some(name) = &self.name {
+            if name.is_empty() || name.contains(['/', '\\']) {
+                eprintln!("Invalid input name \"{name}\"!");
+                return ExitCode::from(EXIT_USAGE);
+            }
+        }
+
+        let report = match (input_path, self.example) {
+            (Some(path), _) => year.report_day_with_input(day, &path, config),
+            (None, Some(index)) => year.report_example(day, index as usize, config),
+            (None, None) => year.report_day(day, self.name.as_deref(), config),
+        };
+
+        match format {
+            Format::Text => report.print(),
+            Format::Json => println!("{}", report.to_json()),
+        }
+
+        if format == Format::Text && self.target.is_today() {
+            if let Some((year, day, seconds)) = calendar::get_next_unlock(calendar::unix_time()) {
+                println!(
+                    "\nYear {year}, Day {day} unlocks in {}",
+                    calendar::format_countdown(seconds)
+                );
+            }
+        }
+
+        match report.status {
+            Status::Completed(answers) if answers.part_1.is_ok() && answers.part_2.is_ok() => {
+                ExitCode::from(EXIT_SUCCESS)
+            }
+            _ => ExitCode::from(EXIT_FAILURE),
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    /// Benchmark every day of every year, instead of a single day
+    #[arg(short, long, conflicts_with_all = ["target", "year", "day"])]
+    all: bool,
+
+    /// How many times each day is measured
+    #[arg(short, long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// How many times each day is run before it's measured
+    #[arg(short, long, default_value_t = 5)]
+    warmup: u32,
+}
+
+impl BenchArgs {
+    fn execute(self, format: Format, config: &Config) -> ExitCode {
+        let years = Year::create_years();
+        let runs = self.runs as usize;
+        let warmup = self.warmup as usize;
+
+        let reports = if self.all {
+            years
+                .iter()
+                .flat_map(|year| bench_year(year, runs, warmup, config))
+                .collect()
+        } else {
+            let (year, day) = unwrap_or_else!(self.target.select(&years), error: e, {
+                eprintln!("{e}");
+                return ExitCode::from(EXIT_USAGE);
+            });
+
+            let input = unwrap_or_else!(year.get_input(day, None, config), error: e, {
+                eprintln!("An error occured: {e}");
+                return ExitCode::from(EXIT_FAILURE);
+            });
+
+            vec![bench_day(year, day, &input, runs, warmup)]
+        };
+
+        for (i, report) in reports.iter().enumerate() {
+            match format {
+                Format::Text if i == 0 => report.print(),
+                Format::Text => {
+                    println!();
+                    report.print();
+                }
+                Format::Json => println!("{}", report.to_json()),
+            }
+        }
+
+        if reports
+            .iter()
+            .any(|report| matches!(report.status, BenchStatus::Failed(_)))
+        {
+            ExitCode::from(EXIT_FAILURE)
+        } else {
+            ExitCode::from(EXIT_SUCCESS)
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+}
+
+impl VerifyArgs {
+    fn execute(self, format: Format, config: &Config) -> ExitCode {
+        let years = Year::create_years();
+
+        let reports = if self.target.is_empty() {
+            years
+                .iter()
+                .flat_map(|year| year.report_days(config))
+                .collect()
+        } else {
+            let (year, day) = unwrap_or_else!(self.target.select(&years), error: e, {
+                eprintln!("{e}");
+                return ExitCode::from(EXIT_USAGE);
+            });
+
+            vec![year.report_day(day, None, config)]
+        };
+
+        print_reports(&reports, format);
+
+        if format == Format::Text {
+            let verdicts: Vec<Verdict> = reports
+                .iter()
+                .flat_map(DayReport::verdicts)
+                .flatten()
+                .collect();
+            let correct = verdicts.iter().filter(|v| **v == Verdict::Correct).count();
+            let unknown = verdicts.iter().filter(|v| **v == Verdict::Unknown).count();
+
+            println!(
+                "\nParts Verified: {correct} Correct, {} Wrong, {unknown} Unknown",
+                verdicts.len() - correct - unknown
+            );
+        }
+
+        if reports
+            .iter()
+            .any(|report| report.has_wrong_answer() || report.has_failed())
+        {
+            ExitCode::from(EXIT_FAILURE)
+        } else {
+            ExitCode::from(EXIT_SUCCESS)
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct CompareArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+}
+
+impl CompareArgs {
+    fn execute(self, format: Format, config: &Config) -> ExitCode {
+        let years = Year::create_years();
+
+        let (year, day) = unwrap_or_else!(self.target.select(&years), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_USAGE);
+        });
+
+        let reports = unwrap_or_else!(year.compare_inputs(day, config), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_FAILURE);
+        });
+
+        match format {
+            Format::Text => print_comparison(&reports),
+            Format::Json => print_reports(&reports, format),
+        }
+
+        if reports
+            .iter()
+            .any(|report| report.has_wrong_answer() || report.has_failed())
+        {
+            ExitCode::from(EXIT_FAILURE)
+        } else {
+            ExitCode::from(EXIT_SUCCESS)
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct WatchArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    /// How often the files are checked for changes, in milliseconds
+    #[arg(long, default_value_t = 500, value_parser = clap::value_parser!(u64).range(10..))]
+    interval: u64,
+}
+
+impl WatchArgs {
+    fn execute(self, format: Format, config: &Config) -> ExitCode {
+        let years = Year::create_years();
+
+        let (year, day) = unwrap_or_else!(self.target.select(&years), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_USAGE);
+        });
+
+        let mut watcher = Watcher::new(year, day, config, Duration::from_millis(self.interval));
+        let mut previous = Vec::new();
+        let mut changes: Vec<PathBuf> = Vec::new();
+        let mut run = 1;
+
+        loop {
+            let reports = watcher.run();
+
+            match format {
+                Format::Text => {
+                    clear().unwrap();
+                    println!(
+                        "Watching Year {}, Day {}: {} (Run {run}, press Ctrl-C to stop)",
+                        year.year, day.day, day.name
+                    );
+
+                    for path in &changes {
+                        println!("Changed: \"{}\"", path.display());
+                    }
+
+                    println!();
+                    print_watch_table(&reports, &previous);
+                    print_summary(&reports, DayReport::get_input_label);
+                }
+                Format::Json => print_reports(&reports, format),
+            }
+
+            previous = reports;
+            changes = watcher.wait_for_change();
+            run += 1;
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct FetchArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    /// Download the input for every day of every year, instead of a single day
+    #[arg(short, long, conflicts_with_all = ["target", "year", "day"])]
+    all: bool,
+}
+
+impl FetchArgs {
+    fn execute(self, format: Format, config: &Config) -> ExitCode {
+        let years = Year::create_years();
+
+        let days: Vec<(&Year, &Day)> = if self.all {
+            years
+                .iter()
+                .flat_map(|year| year.days.iter().map(move |day| (year, *day)))
+                .collect()
+        } else {
+            let (year, day) = unwrap_or_else!(self.target.select(&years), error: e, {
+                eprintln!("{e}");
+                return ExitCode::from(EXIT_USAGE);
+            });
+
+            vec![(year, day)]
+        };
+
+        let client = unwrap_or_else!(Client::new(config), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_USAGE);
+        });
+
+        let mut failed = false;
+
+        for (year, day) in days {
+            let report = fetch_input(config, &client, year.year, day.day);
+            failed |= report.status.is_err();
+
+            match format {
+                Format::Text => report.print(),
+                Format::Json => println!("{}", report.to_json()),
+            }
+        }
+
+        if failed {
+            ExitCode::from(EXIT_FAILURE)
+        } else {
+            ExitCode::from(EXIT_SUCCESS)
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    /// Which part's answer to submit
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Submit this answer instead of running the day (e.g. for answers drawn on a CRT)
+    #[arg(short, long)]
+    answer: Option<String>,
+
+    /// Submit the answer even if earlier submissions show it's wrong
+    #[arg(long)]
+    force: bool,
+}
+
+impl SubmitArgs {
+    fn get_answer(&self, year: &Year, day: &Day, config: &Config) -> Result<String, BoxedError> {
+        if let Some(answer) = &self.answer {
+            return Ok(answer.trim().to_string());
+        }
+
+        let answers = match day.report(&year.get_input(day, None, config)?, config).0 {
+            Status::Completed(answers) => answers,
+            Status::Skipped(reason) => return_err!("Skipped: {}", reason),
+            Status::Failed(error) => return_err!("An error occured: {}", error),
+        };
+
+        let answer = match self.part {
+            1 => answers.part_1,
+            _ => answers.part_2,
+        };
+
+        let answer = unwrap_or_return!(answer, error: e, "Part {} has no answer: {}", self.part, e);
+
+        if answer.trim().contains('\n') {
+            return_err!(
+                "This answer is drawn over multiple lines, so it can't be submitted as it is. Read it, and then submit it with \"--answer\":\n{}",
+                answer.trim_end()
+            );
+        }
+
+        Ok(answer.trim().to_string())
+    }
+
+    fn execute(self, format: Format, config: &Config) -> ExitCode {
+        let years = Year::create_years();
+
+        let (year, day) = unwrap_or_else!(self.target.select(&years), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_USAGE);
+        });
+
+        if config.read_only {
+            eprintln!("Answers can't be submitted in read-only mode, since the submission can't be recorded");
+            return ExitCode::from(EXIT_USAGE);
+        }
+
+        let client = unwrap_or_else!(Client::new(config), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_USAGE);
+        });
+
+        let answer = unwrap_or_else!(self.get_answer(year, day, config), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_FAILURE);
+        });
+
+        let log_path = config.get_submissions_path(year.year);
+        let mut log = unwrap_or_else!(SubmissionLog::load(&log_path), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_FAILURE);
+        });
+
+        let now = calendar::unix_time();
+
+        if !self.force {
+            unwrap_or_else!(log.check(day.day, self.part, &answer, now), error: e, {
+                eprintln!("Not submitting: {e}");
+                return ExitCode::from(EXIT_FAILURE);
+            });
+        }
+
+        let html = unwrap_or_else!(client.submit_answer(year.year, day.day, self.part, &answer), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_FAILURE);
+        });
+
+        let response = Response::parse(&html);
+        let submission = log
+            .record(day.day, self.part, &answer, &response, now)
+            .clone();
+
+        if let Err(e) = log.save(&log_path) {
+            eprintln!("Warning: {e}");
+        }
+
+        match format {
+            Format::Text => submission.print(&response.message),
+            Format::Json => println!("{}", submission.to_json(year.year, &response.message)),
+        }
+
+        if submission.outcome == Outcome::Correct {
+            ExitCode::from(EXIT_SUCCESS)
+        } else {
+            ExitCode::from(EXIT_FAILURE)
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct DescribeArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    /// Where to load the puzzle page from, either as a URL or as the path of a saved page
+    /// [default: The puzzle's page on the Advent of Code server]
+    #[arg(long, value_name = "URL|PATH")]
+    from: Option<String>,
+
+    /// Replace the description and examples if they already exist
+    #[arg(long)]
+    force: bool,
+
+    /// The root of this crate, where the examples are saved
+    /// [default: The nearest folder with a Cargo.toml, starting from the current one]
+    #[arg(long)]
+    root: Option<PathBuf>,
+}
+
+impl DescribeArgs {
+    fn execute(self, config: &Config) -> ExitCode {
+        let years = Year::create_years();
+
+        let (year, day) = unwrap_or_else!(self.target.select(&years), error: e, {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_USAGE);
+        });
+
+        let root = match self.root {
+            Some(root) => Ok(root),
+            None => find_crate_root(),
+        };
+
+        let written = root.and_then(|root| {
+            let html = load_page(config, year.year, day.day, self.from.as_deref())?;
+            describe_day(&root, config, year.year, day.day, &html, self.force)
+        });
+
+        match written {
+            Ok((written, skipped)) => {
+                for path in written {
+                    println!("Wrote \"{path}\"");
+                }
+
+                for path in skipped {
+                    println!(
+                        "Skipped \"{path}\", as it already exists (use --force to replace it)"
+                    );
+                }
+
+                ExitCode::from(EXIT_SUCCESS)
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::from(EXIT_FAILURE)
+            }
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// The year of the new day (e.g. "2022")
+    #[arg(value_parser = clap::value_parser!(u32).range(2015..))]
+    year: u32,
+
+    /// The number of the new day (e.g. "15"), up to 25 (or 12, from 2025 on)
+    #[arg(value_parser = clap::value_parser!(u64).range(1..=25))]
+    day: u64,
+
+    /// The title of the new day (e.g. "Beacon Exclusion Zone")
+    title: String,
+
+    /// The root of this crate, where the new day's module is created
+    /// [default: The nearest folder with a Cargo.toml, starting from the current one]
+    #[arg(long)]
+    root: Option<PathBuf>,
+}
+
+impl NewArgs {
+    fn execute(self, config: &Config) -> ExitCode {
+        let title = self.title.trim();
+
+        if title.is_empty() {
+            eprintln!("The title can't be empty!");
+            return ExitCode::from(EXIT_USAGE);
+        }
+
+        let last_day = calendar::get_last_day(self.year);
+        if self.day as usize > last_day {
+            eprintln!("{} only has {} days!", self.year, last_day);
+            return ExitCode::from(EXIT_USAGE);
+        }
+
+        let root = match self.root {
+            Some(root) => Ok(root),
+            None => find_crate_root(),
+        };
+
+        let written =
+            root.and_then(|root| create_day(&root, config, self.year, self.day as usize, title));
+
+        match written {
+            Ok(paths) => {
+                for path in paths {
+                    println!("Wrote \"{path}\"");
+                }
+
+                ExitCode::from(EXIT_SUCCESS)
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::from(EXIT_FAILURE)
+            }
+        }
+    }
+}
+
+fn print_reports(reports: &[DayReport], format: Format) {
+    match format {
+        Format::Text if reports.len() == 1 => reports[0].print(),
+        Format::Text => print_table(reports),
+        Format::Json => {
+            for report in reports {
+                println!("{}", report.to_json());
+            }
+        }
+    }
+}
+
+fn run_all(format: Format, config: &Config) -> ExitCode {
+    let reports: Vec<DayReport> = Year::create_years()
+        .iter()
+        .flat_map(|year| year.report_days(config))
+        .collect();
+
+    match format {
+        Format::Text => print_table(&reports),
+        Format::Json => print_reports(&reports, format),
+    }
+
+    if reports.iter().any(DayReport::has_failed) {
+        ExitCode::from(EXIT_FAILURE)
+    } else {
+        ExitCode::from(EXIT_SUCCESS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::{Cli, Command, RunArgs};
+
+    fn get_run_args(args: &[&str]) -> RunArgs {
+        let cli = Cli::try_parse_from([&["advent_of_code", "run"], args].concat()).unwrap();
+
+        match cli.command {
+            Some(Command::Run(args)) => args,
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_target(args: &[&str]) -> Result<(String, String), String> {
+        let cli = Cli::try_parse_from([&["advent_of_code", "run"], args].concat()).unwrap();
+
+        match cli.command {
+            Some(Command::Run(args)) => args.target.get_target().map_err(|e| e.to_string()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn target_formats() {
+        let expected = Ok(("2022".to_string(), "9".to_string()));
+
+        assert_eq!(expected, get_target(&["2022/9"]));
+        assert_eq!(expected, get_target(&["2022", "9"]));
+        assert_eq!(expected, get_target(&["--year", "2022", "--day", "9"]));
+        assert_eq!(expected, get_target(&["2022", "--day", "9"]));
+
+        let today = Ok(("today".to_string(), "today".to_string()));
+        assert_eq!(today, get_target(&["today"]));
+        assert_eq!(
+            Ok(("2022".to_string(), "Today".to_string())),
+            get_target(&["2022", "Today"])
+        );
+    }
+
+    #[test]
+    fn invalid_targets() {
+        assert!(get_target(&["2022"]).is_err());
+        assert!(get_target(&["2022/9", "--day", "9"]).is_err());
+        assert!(get_target(&["--day", "9"]).is_err());
+        assert!(get_target(&["2022", "9", "10"]).is_err());
+    }
+
+    #[test]
+    fn input_paths() {
+        let get_input_path = |args: &[&str]| {
+            let mut args = get_run_args(args);
+            let input_path = args.get_input_path().map_err(|e| e.to_string());
+
+            (input_path, args.target.get_target().is_ok())
+        };
+
+        assert_eq!((Ok(None), true), get_input_path(&["2022", "14"]));
+        assert_eq!(
+            (Ok(Some("-".to_string())), true),
+            get_input_path(&["2022", "14", "-"])
+        );
+        assert_eq!(
+            (Ok(Some("-".to_string())), true),
+            get_input_path(&["2022/14", "-"])
+        );
+        assert_eq!(
+            (Ok(Some("edge_case.txt".to_string())), true),
+            get_input_path(&["2022/14", "--input", "edge_case.txt"])
+        );
+        assert!(
+            get_input_path(&["2022/14", "-", "--input", "edge_case.txt"])
+                .0
+                .is_err()
+        );
+        assert!(get_input_path(&["2022/14", "-", "--example"]).0.is_err());
+        assert!(get_input_path(&["2022", "1", "-", "--name", "foo"])
+            .0
+            .is_err());
+        assert!(Cli::try_parse_from([
+            "advent_of_code",
+            "run",
+            "2022/14",
+            "--name",
+            "alice",
+            "--example"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn read_only_flag() {
+        let get_read_only = |args: &[&str]| {
+            Cli::try_parse_from([&["advent_of_code", "all"], args].concat())
+                .map(|cli| cli.config.read_only)
+                .ok()
+        };
+
+        assert_eq!(Some(None), get_read_only(&[]));
+        assert_eq!(Some(Some(true)), get_read_only(&["--read-only"]));
+        assert_eq!(Some(Some(true)), get_read_only(&["--read-only=yes"]));
+        assert_eq!(Some(Some(false)), get_read_only(&["--read-only=0"]));
+        assert_eq!(Some(Some(false)), get_read_only(&["--read-only=false"]));
+        assert_eq!(None, get_read_only(&["--read-only=maybe"]));
+    }
+
+    #[test]
+    fn examples() {
+        let get_example = |args: &[&str]| get_run_args(args).example;
+
+        assert_eq!(None, get_example(&["2022/9"]));
+        assert_eq!(Some(1), get_example(&["2022/9", "--example"]));
+        assert_eq!(Some(2), get_example(&["--example=2", "2022/9"]));
+        assert_eq!(Some(1), get_example(&["-e", "2022", "9"]));
+
+        assert!(Cli::try_parse_from(["advent_of_code", "run", "2022/9", "-e=0"]).is_err());
+        assert!(Cli::try_parse_from([
+            "advent_of_code",
+            "run",
+            "2022/9",
+            "-e",
+            "-i",
+            "edge_case.txt"
+        ])
+        .is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::macros::*;
+use crate::BoxedError;
+
+const USER_AGENT: &str = concat!(
+    "github.com/BobbyShmurner/advent_of_code v",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Talks to the Advent of Code server (or whatever server `base_url` points to) as the logged in user
+pub(crate) struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, BoxedError> {
+        if config.session.is_none() {
+            return_err!("No session token was given! Set it with \"--session\", the AOC_SESSION environment variable, or \"session\" in the config file");
+        }
+
+        Ok(Self::new_optional(config))
+    }
+
+    /// Like `Client::new`, but for pages that can also be seen without logging in.
+    /// The session token is still used if there is one
+    pub fn new_optional(config: &Config) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(USER_AGENT)
+            .build();
+
+        Self {
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            session: config.session.clone(),
+            agent,
+        }
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        let request = self.agent.request(method, url);
+
+        match &self.session {
+            Some(session) => request.set("Cookie", &format!("session={session}")),
+            None => request,
+        }
+    }
+
+    pub fn get_puzzle_url(&self, year: u32, day_num: usize) -> String {
+        format!("{}/{year}/day/{day_num}", self.base_url)
+    }
+
+    pub fn get_page(&self, url: &str) -> Result<String, BoxedError> {
+        read_response(url, self.request("GET", url).call())
+    }
+
+    pub fn get_input(&self, year: u32, day_num: usize) -> Result<String, BoxedError> {
+        let url = format!("{}/{year}/day/{day_num}/input", self.base_url);
+        let response = self.request("GET", &url).call();
+
+        read_response(&url, response)
+    }
+
+    /// Posts an answer, returning the HTML page that says whether it was right
+    pub fn submit_answer(
+        &self,
+        year: u32,
+        day_num: usize,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, BoxedError> {
+        let url = format!("{}/{year}/day/{day_num}/answer", self.base_url);
+        let response = self
+            .request("POST", &url)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        read_response(&url, response)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, BoxedError> {
+    match response {
+        Ok(response) => Ok(unwrap_or_return!(
+            response.into_string(),
+            error: e,
+            "Failed to read the response from \"{}\"\nReason: {}",
+            url,
+            e
+        )),
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            return_err!(
+                "Request to \"{}\" failed with status {}\nReason: {}",
+                url,
+                code,
+                body.trim()
+            );
+        }
+        Err(e) => return_err!("Request to \"{}\" failed\nReason: {}", url, e),
+    }
+}
+
+/// A fake Advent of Code server that answers a single request, for testing without the network
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Returns the base URL of the server, and a handle that gives back the raw request once it's been answered
+    pub fn serve_once(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = read_request(&mut stream);
+
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn read_request(stream: &mut impl Read) -> String {
+        let mut request = Vec::new();
+        let mut buffer = [0; 1024];
+
+        loop {
+            let read = stream.read(&mut buffer).unwrap();
+            request.extend_from_slice(&buffer[..read]);
+
+            let text = String::from_utf8_lossy(&request);
+            if let Some((headers, body)) = text.split_once("\r\n\r\n") {
+                let length = headers
+                    .lines()
+                    .find_map(|line| {
+                        line.to_lowercase()
+                            .strip_prefix("content-length: ")
+                            .map(str::to_string)
+                    })
+                    .and_then(|length| length.trim().parse().ok())
+                    .unwrap_or(0);
+
+                if body.len() >= length {
+                    return text.to_string();
+                }
+            }
+
+            if read == 0 {
+                return String::from_utf8_lossy(&request).to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stub::serve_once;
+    use super::Client;
+    use crate::config::Config;
+
+    fn create_client(base_url: &str) -> Client {
+        Client::new(&Config {
+            session: Some("abc123".to_string()),
+            base_url: base_url.to_string(),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn get_input() {
+        let (base_url, handle) = serve_once("200 OK", "1000\n2000\n\n3000\n");
+        let input = create_client(&base_url).get_input(2022, 1).unwrap();
+        let request = handle.join().unwrap();
+
+        assert_eq!("1000\n2000\n\n3000\n", input);
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc123\r\n"));
+    }
+
+    #[test]
+    fn failed_request() {
+        let (base_url, handle) = serve_once(
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        );
+        let result = create_client(&base_url).get_input(2022, 1);
+        handle.join().unwrap();
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("status 400"));
+        assert!(error.contains("Please log in"));
+
+        assert!(Client::new(&Config::default()).is_err());
+    }
+}
@@ -0,0 +1,383 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use clap::builder::BoolishValueParser;
+use clap::Args;
+use serde::Deserialize;
+
+use crate::macros::*;
+use crate::BoxedError;
+
+pub const DEFAULT_INPUT_ROOT: &str = "./inputs";
+pub const DEFAULT_INPUT_PATTERN: &str = "{year}/day_{day}.txt";
+pub const DEFAULT_CONFIG_PATH: &str = "./aoc.toml";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Options for where inputs are kept, and for how Advent of Code is reached. Each one can be given
+/// as a flag, as an environment variable, or in the config file, in that order of priority
+#[derive(Args, Default)]
+pub struct ConfigArgs {
+    /// The config file to load [default: ./aoc.toml, if it exists]
+    #[arg(long, global = true, env = "AOC_CONFIG")]
+    pub config: Option<PathBuf>,
+
+    /// The folder that contains the puzzle inputs [default: ./inputs]
+    #[arg(long, global = true, env = "AOC_INPUTS")]
+    pub inputs: Option<PathBuf>,
+
+    /// The path of each input within the inputs folder, where "{year}" and "{day}" are replaced
+    /// [default: {year}/day_{day}.txt]
+    #[arg(long, global = true, env = "AOC_INPUT_PATTERN")]
+    pub input_pattern: Option<String>,
+
+    /// Never create any folders or files, and treat missing inputs as errors instead. Use
+    /// "--read-only=false" (or "no", "0" etc) to turn it off when the config file turns it on [default: false]
+    #[arg(
+        long,
+        global = true,
+        env = "AOC_READ_ONLY",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        value_parser = BoolishValueParser::new()
+    )]
+    pub read_only: Option<bool>,
+
+    /// The session cookie used to download inputs from Advent of Code
+    #[arg(long, global = true, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+
+    /// The Advent of Code server to talk to [default: https://adventofcode.com]
+    #[arg(long, global = true, env = "AOC_BASE_URL")]
+    pub base_url: Option<String>,
+
+    /// How many seconds each day can run for before it's stopped, or 0 for no limit [default: 0]. A day that
+    /// doesn't check if it's been cancelled keeps running in the background until it finishes
+    #[arg(long, global = true, env = "AOC_TIMEOUT")]
+    pub timeout: Option<u64>,
+}
+
+/// The contents of the config file:
+///
+/// ```toml
+/// input_root = "../private-inputs"
+/// input_pattern = "{year}/day_{day}.txt"
+/// read_only = true
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// timeout = 30
+/// ```
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    input_root: Option<PathBuf>,
+    input_pattern: Option<String>,
+    read_only: Option<bool>,
+    session: Option<String>,
+    base_url: Option<String>,
+    timeout: Option<u64>,
+}
+
+impl ConfigFile {
+    fn load(path: &Path) -> Result<Self, BoxedError> {
+        let contents = unwrap_or_return!(
+            fs::read_to_string(path),
+            error: e,
+            io: "Failed to open config file (Path: \"{}\")\nReason: {}",
+            path.display(),
+            e
+        );
+
+        let mut config: Self = unwrap_or_return!(
+            toml::from_str(&contents),
+            error: e,
+            "Invalid config file (Path: \"{}\")\nReason: {}",
+            path.display(),
+            e
+        );
+
+        // A relative input root is relative to the config file, not to wherever the binary was run from
+        if let (Some(root), Some(folder)) = (&config.input_root, path.parent()) {
+            config.input_root = Some(folder.join(root));
+        }
+
+        Ok(config)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub input_root: PathBuf,
+    pub input_pattern: String,
+    pub read_only: bool,
+    pub session: Option<String>,
+    pub base_url: String,
+    /// How long each day can run for. `None` if there's no limit
+    pub timeout: Option<Duration>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            input_root: PathBuf::from(DEFAULT_INPUT_ROOT),
+            input_pattern: DEFAULT_INPUT_PATTERN.to_string(),
+            read_only: false,
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: None,
+        }
+    }
+}
+
+impl Config {
+    pub fn load(args: &ConfigArgs) -> Result<Self, BoxedError> {
+        let file = match &args.config {
+            Some(path) => ConfigFile::load(path)?,
+            None if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+                ConfigFile::load(Path::new(DEFAULT_CONFIG_PATH))?
+            }
+            None => ConfigFile::default(),
+        };
+
+        let default = Config::default();
+
+        let config = Config {
+            input_root: args
+                .inputs
+                .clone()
+                .or(file.input_root)
+                .unwrap_or(default.input_root),
+            input_pattern: args
+                .input_pattern
+                .clone()
+                .or(file.input_pattern)
+                .unwrap_or(default.input_pattern),
+            read_only: args
+                .read_only
+                .or(file.read_only)
+                .unwrap_or(default.read_only),
+            session: args.session.clone().or(file.session),
+            base_url: args
+                .base_url
+                .clone()
+                .or(file.base_url)
+                .unwrap_or(default.base_url),
+            timeout: args
+                .timeout
+                .or(file.timeout)
+                .filter(|seconds| *seconds > 0)
+                .map(Duration::from_secs),
+        };
+
+        if !config.input_pattern.contains("{day}") {
+            return_err!(
+                "Invalid input pattern \"{}\": It must contain \"{{day}}\"",
+                config.input_pattern
+            );
+        }
+
+        Ok(config)
+    }
+
+    pub fn get_input_path(&self, year: u32, day_num: usize) -> PathBuf {
+        let file = self
+            .input_pattern
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day_num.to_string());
+
+        self.input_root.join(file)
+    }
+
+    /// Examples sit next to the input they're for, so "day_9.txt" has "day_9.example.txt",
+    /// "day_9.example_2.txt" and so on
+    pub fn get_example_path(&self, year: u32, day_num: usize, index: usize) -> PathBuf {
+        let path = self.get_input_path(year, day_num);
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+
+        let name = match index {
+            0 | 1 => format!("{stem}.example.txt"),
+            _ => format!("{stem}.example_{index}.txt"),
+        };
+
+        path.with_file_name(name)
+    }
+
+    /// The known answers for an example sit next to it, so "day_9.example.txt" has "day_9.example.toml"
+    pub fn get_example_answers_path(&self, year: u32, day_num: usize, index: usize) -> PathBuf {
+        self.get_example_path(year, day_num, index)
+            .with_extension("toml")
+    }
+
+    /// Named inputs (such as each teammate's own input) sit in a folder named after the day's input,
+    /// so "day_9.txt" has "day_9/alice.txt", "day_9/bob.txt" and so on
+    pub fn get_named_inputs_folder(&self, year: u32, day_num: usize) -> PathBuf {
+        self.get_input_path(year, day_num).with_extension("")
+    }
+
+    pub fn get_named_input_path(&self, year: u32, day_num: usize, name: &str) -> PathBuf {
+        let path = self.get_input_path(year, day_num);
+        let file = match path.extension() {
+            Some(extension) => format!("{name}.{}", extension.to_string_lossy()),
+            None => name.to_string(),
+        };
+
+        self.get_named_inputs_folder(year, day_num).join(file)
+    }
+
+    /// The known answers for a named input sit next to it, so "day_9/alice.txt" has "day_9/alice.toml"
+    pub fn get_named_answers_path(&self, year: u32, day_num: usize, name: &str) -> PathBuf {
+        self.get_named_inputs_folder(year, day_num)
+            .join(format!("{name}.toml"))
+    }
+
+    pub fn get_description_path(&self, year: u32, day_num: usize) -> PathBuf {
+        self.get_input_path(year, day_num).with_extension("md")
+    }
+
+    /// Files for a whole year sit in the year's folder from the input pattern, so "{year}/day_{day}.txt" has
+    /// "2022/answers.toml". If every year shares a folder, the year is added to the name instead, so
+    /// "aoc-{year}-{day}.in" has "answers_2022.toml"
+    fn get_year_file_path(&self, year: u32, name: &str) -> PathBuf {
+        let pattern = Path::new(&self.input_pattern);
+        let mut folder = self.input_root.clone();
+        let mut has_year = false;
+
+        for component in pattern.parent().into_iter().flat_map(Path::components) {
+            let component = component.as_os_str().to_string_lossy();
+
+            // Anything from here on is a folder for each day
+            if component.contains("{day}") {
+                break;
+            }
+
+            has_year |= component.contains("{year}");
+            folder.push(component.replace("{year}", &year.to_string()));
+        }
+
+        if has_year {
+            folder.join(format!("{name}.toml"))
+        } else {
+            folder.join(format!("{name}_{year}.toml"))
+        }
+    }
+
+    pub fn get_answers_path(&self, year: u32) -> PathBuf {
+        self.get_year_file_path(year, "answers")
+    }
+
+    pub fn get_submissions_path(&self, year: u32) -> PathBuf {
+        self.get_year_file_path(year, "submissions")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use std::{env, fs};
+
+    use super::{Config, ConfigArgs};
+
+    #[test]
+    fn input_paths() {
+        let config = Config::default();
+
+        assert_eq!(
+            PathBuf::from("./inputs/2022/day_9.txt"),
+            config.get_input_path(2022, 9)
+        );
+        assert_eq!(
+            PathBuf::from("./inputs/2022/day_9.example.txt"),
+            config.get_example_path(2022, 9, 1)
+        );
+        assert_eq!(
+            PathBuf::from("./inputs/2022/day_9/alice.txt"),
+            config.get_named_input_path(2022, 9, "alice")
+        );
+        assert_eq!(
+            PathBuf::from("./inputs/2022/day_9/alice.toml"),
+            config.get_named_answers_path(2022, 9, "alice")
+        );
+
+        let config = Config {
+            input_root: PathBuf::from("/private"),
+            input_pattern: "aoc-{year}-{day}.in".to_string(),
+            read_only: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            PathBuf::from("/private/aoc-2022-14.in"),
+            config.get_input_path(2022, 14)
+        );
+        assert_eq!(
+            PathBuf::from("/private/aoc-2022-14.example_2.txt"),
+            config.get_example_path(2022, 14, 2)
+        );
+        assert_eq!(
+            PathBuf::from("/private/aoc-2022-14.example_2.toml"),
+            config.get_example_answers_path(2022, 14, 2)
+        );
+        assert_eq!(
+            PathBuf::from("/private/aoc-2022-14.md"),
+            config.get_description_path(2022, 14)
+        );
+        assert_eq!(
+            PathBuf::from("/private/answers_2022.toml"),
+            config.get_answers_path(2022)
+        );
+
+        let config = Config {
+            input_root: PathBuf::from("/private"),
+            input_pattern: "aoc/{year}/day_{day}/input.txt".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            PathBuf::from("/private/aoc/2022/answers.toml"),
+            config.get_answers_path(2022)
+        );
+        assert_eq!(
+            PathBuf::from("/private/aoc/2022/submissions.toml"),
+            config.get_submissions_path(2022)
+        );
+    }
+
+    #[test]
+    fn override_config_file() {
+        let path = env::temp_dir().join(format!("aoc_config_{}.toml", std::process::id()));
+        fs::write(&path, "read_only = true\ntimeout = 30\n").unwrap();
+
+        let args = ConfigArgs {
+            config: Some(path.clone()),
+            ..Default::default()
+        };
+        let config = Config::load(&args).unwrap();
+        assert!(config.read_only);
+        assert_eq!(Some(Duration::from_secs(30)), config.timeout);
+
+        let args = ConfigArgs {
+            config: Some(path.clone()),
+            read_only: Some(false),
+            timeout: Some(0),
+            ..Default::default()
+        };
+        let config = Config::load(&args).unwrap();
+        assert!(!config.read_only);
+        assert_eq!(None, config.timeout);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        let args = ConfigArgs {
+            config: Some(PathBuf::from("/dev/null")),
+            input_pattern: Some("{year}.txt".to_string()),
+            ..Default::default()
+        };
+
+        assert!(Config::load(&args).is_err());
+    }
+}
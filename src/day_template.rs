@@ -1,26 +1,46 @@
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
-pub fn execute(input: &str) -> DayReturnType {
-    return_err!("Code For This Day Is Not Complete!");
+pub struct DayN;
+
+impl Solution for DayN {
+    type Input = ();
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(_input: &str) -> Result<(), BoxedError> {
+        Ok(())
+    }
+
+    fn part_1(_input: &()) -> Result<String, BoxedError> {
+        return_err!(not_implemented);
+    }
+
+    fn part_2(_input: &()) -> Result<String, BoxedError> {
+        return_err!(not_implemented);
+    }
 }
 
+register_day!(0, 0, "Title", DayN);
+
 #[cfg(test)]
 mod tests {
+    use super::DayN;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
+    #[ignore = "Not complete yet"]
     fn part1_example() {
-        let input = r#""#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = DayN::execute(example!(0, 0)).unwrap().part_1.unwrap();
         assert_eq!("Not Implemented", result);
     }
 
     #[test]
+    #[ignore = "Not complete yet"]
     fn part2_example() {
-        let input = r#""#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = DayN::execute(example!(0, 0)).unwrap().part_2.unwrap();
         assert_eq!("Not Implemented", result);
     }
 }
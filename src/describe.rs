@@ -0,0 +1,309 @@
+use std::fs;
+use std::path::Path;
+
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+use crate::client::Client;
+use crate::config::Config;
+use crate::macros::*;
+use crate::scaffold::get_example_path;
+use crate::BoxedError;
+
+lazy_static! {
+    static ref ARTICLE: Regex =
+        Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    static ref PRE: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    static ref EXAMPLE: Regex =
+        Regex::new(r"(?s)<p>((?:[^<]|<[^/]|</[^p])*?)</p>\s*<pre><code>(.*?)</code></pre>")
+            .unwrap();
+    static ref HEADING: Regex = Regex::new(r"(?s)<h2[^>]*>(.*?)</h2>").unwrap();
+    static ref EMPHASIZED_CODE: Regex = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+    static ref CODE: Regex = Regex::new(r"(?s)<code>(.*?)</code>").unwrap();
+    static ref EM: Regex = Regex::new(r"(?s)<em[^>]*>(.*?)</em>").unwrap();
+    static ref LINK: Regex = Regex::new(r#"(?s)<a [^>]*href="([^"]*)"[^>]*>(.*?)</a>"#).unwrap();
+    static ref LIST_ITEM: Regex = Regex::new(r"(?s)<li>(.*?)</li>\s*").unwrap();
+    static ref PARAGRAPH: Regex = Regex::new(r"(?s)<(p|ul)>(.*?)</(?:p|ul)>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref BLANK_LINES: Regex = Regex::new(r"\n{3,}").unwrap();
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The text of a `<pre><code>` block, exactly as it would be typed as an input
+fn code_block_text(html: &str) -> String {
+    decode_entities(&TAG.replace_all(html, ""))
+}
+
+/// Converts the puzzle description in an Advent of Code page to Markdown.
+/// Only the tags that are used in puzzle descriptions are converted, and any others are dropped
+pub fn html_to_markdown(html: &str) -> String {
+    let mut markdown = Vec::new();
+
+    for article in ARTICLE.captures_iter(html) {
+        // Code blocks are swapped out first, so that nothing inside them is treated as Markdown
+        let mut blocks = Vec::new();
+        let text = PRE.replace_all(&article[1], |caps: &Captures| {
+            blocks.push(code_block_text(&caps[1]));
+            format!("\n\n\u{0}{}\u{0}\n\n", blocks.len() - 1)
+        });
+
+        let text = HEADING.replace_all(&text, "## $1\n\n");
+        let text = EMPHASIZED_CODE.replace_all(&text, "**`$1`**");
+        let text = CODE.replace_all(&text, "`$1`");
+        let text = EM.replace_all(&text, "**$1**");
+        let text = LINK.replace_all(&text, "[$2]($1)");
+        let text = LIST_ITEM.replace_all(&text, "- $1\n");
+        let text = PARAGRAPH.replace_all(&text, "$2\n\n");
+        let mut text = decode_entities(&TAG.replace_all(&text, ""));
+
+        for (i, block) in blocks.iter().enumerate() {
+            text = text.replace(
+                &format!("\u{0}{i}\u{0}"),
+                &format!("```\n{}\n```", block.trim_end_matches('\n')),
+            );
+        }
+
+        markdown.push(BLANK_LINES.replace_all(text.trim(), "\n\n").to_string());
+    }
+
+    markdown.join("\n\n") + "\n"
+}
+
+/// Finds the example inputs in an Advent of Code page. A code block counts as an example if the
+/// paragraph just before it mentions an example (e.g. "For example:"), which skips over the diagrams
+pub fn extract_examples(html: &str) -> Vec<String> {
+    let mut examples: Vec<String> = Vec::new();
+
+    for article in ARTICLE.captures_iter(html) {
+        for caps in EXAMPLE.captures_iter(&article[1]) {
+            if !caps[1].to_lowercase().contains("example") {
+                continue;
+            }
+
+            let example = code_block_text(&caps[2]);
+            if !examples.contains(&example) {
+                examples.push(example);
+            }
+        }
+    }
+
+    examples
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), BoxedError> {
+    if let Some(folder) = path.parent() {
+        unwrap_or_return!(
+            fs::create_dir_all(folder),
+            error: e,
+            io: "Failed to create folder \"{}\"\nReason: {}",
+            folder.display(),
+            e
+        );
+    }
+
+    unwrap_or_return!(
+        fs::write(path, contents),
+        error: e,
+        io: "Failed to write \"{}\"\nReason: {}",
+        path.display(),
+        e
+    );
+
+    Ok(())
+}
+
+/// Loads a puzzle page from `source`, which is either a URL or the path of a saved page.
+/// Without a source, the page is downloaded from the configured server
+pub(crate) fn load_page(
+    config: &Config,
+    year: u32,
+    day_num: usize,
+    source: Option<&str>,
+) -> Result<String, BoxedError> {
+    let client = Client::new_optional(config);
+
+    match source {
+        Some(url) if url.starts_with("http://") || url.starts_with("https://") => {
+            client.get_page(url)
+        }
+        Some(path) => Ok(unwrap_or_return!(
+            fs::read_to_string(path),
+            error: e,
+            io: "Failed to open page (Path: \"{}\")\nReason: {}",
+            path,
+            e
+        )),
+        None => client.get_page(&client.get_puzzle_url(year, day_num)),
+    }
+}
+
+/// Writes the page's description as Markdown next to the input, and each of its examples to their own file
+/// in the crate at `root` (where the tests load them from). Files that already exist (such as examples that
+/// were fixed by hand) are left alone, unless `force` is set. Returns the paths that were written, and the
+/// paths that were skipped
+pub(crate) fn describe_day(
+    root: &Path,
+    config: &Config,
+    year: u32,
+    day_num: usize,
+    html: &str,
+    force: bool,
+) -> Result<(Vec<String>, Vec<String>), BoxedError> {
+    if config.read_only {
+        return_err!("Can't write the description in read-only mode");
+    }
+
+    let markdown = html_to_markdown(html);
+    if markdown.trim().is_empty() {
+        return_err!("Couldn't find a puzzle description in the page");
+    }
+
+    let mut files = vec![(config.get_description_path(year, day_num), markdown)];
+
+    for (i, example) in extract_examples(html).into_iter().enumerate() {
+        files.push((get_example_path(root, year, day_num, i + 1), example));
+    }
+
+    let mut written = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (path, contents) in files {
+        if path.exists() && !force {
+            skipped.push(path.display().to_string());
+            continue;
+        }
+
+        write_file(&path, &contents)?;
+        written.push(path.display().to_string());
+    }
+
+    Ok((written, skipped))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use crate::config::Config;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 9: Rope Bridge ---</h2><p>Consider a rope with a knot at each end; these knots mark the <em>head</em> and the <em>tail</em> of the rope.</p>
+<p>Due to the aforementioned <a href="https://en.wikipedia.org/wiki/Planck_units#Planck_length" target="_blank">Planck lengths</a>, the head (<code>H</code>) and tail (<code>T</code>) must always be touching:</p>
+<pre><code>....
+.TH.
+....
+</code></pre>
+<p>For example:</p>
+<pre><code>R 4
+U 4
+</code></pre>
+<ul>
+<li>Moving <code>R 4</code> goes right.</li>
+<li>So, there are <code><em>13</em></code> positions &amp; more &lt;here&gt;.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>6354</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now, consider this larger example:</p>
+<pre><code>R 5
+<em>U 8</em>
+</code></pre>
+<p>Another example, which is the same as the first one:</p>
+<pre><code>R 4
+U 4
+</code></pre>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn html_to_markdown() {
+        assert_eq!(
+            r#"## --- Day 9: Rope Bridge ---
+
+Consider a rope with a knot at each end; these knots mark the **head** and the **tail** of the rope.
+
+Due to the aforementioned [Planck lengths](https://en.wikipedia.org/wiki/Planck_units#Planck_length), the head (`H`) and tail (`T`) must always be touching:
+
+```
+....
+.TH.
+....
+```
+
+For example:
+
+```
+R 4
+U 4
+```
+
+- Moving `R 4` goes right.
+- So, there are **`13`** positions & more <here>.
+
+## --- Part Two ---
+
+Now, consider this larger example:
+
+```
+R 5
+U 8
+```
+
+Another example, which is the same as the first one:
+
+```
+R 4
+U 4
+```
+"#,
+            super::html_to_markdown(PAGE)
+        );
+    }
+
+    #[test]
+    fn extract_examples() {
+        assert_eq!(
+            vec!["R 4\nU 4\n".to_string(), "R 5\nU 8\n".to_string()],
+            super::extract_examples(PAGE)
+        );
+    }
+
+    #[test]
+    fn keep_existing_files() {
+        let root = env::temp_dir().join(format!("aoc_describe_{}", std::process::id()));
+        let config = Config {
+            input_root: root.join("private"),
+            ..Default::default()
+        };
+
+        let example_path = root.join("inputs").join("2022").join("day_9.example.txt");
+        fs::create_dir_all(example_path.parent().unwrap()).unwrap();
+        fs::write(&example_path, "R 4\nU 3\n").unwrap();
+
+        let (written, skipped) = super::describe_day(&root, &config, 2022, 9, PAGE, false).unwrap();
+        assert_eq!(
+            vec![
+                root.join("private/2022/day_9.md").display().to_string(),
+                root.join("inputs/2022/day_9.example_2.txt")
+                    .display()
+                    .to_string(),
+            ],
+            written
+        );
+        assert_eq!(vec![example_path.display().to_string()], skipped);
+        assert_eq!("R 4\nU 3\n", fs::read_to_string(&example_path).unwrap());
+
+        let (written, skipped) = super::describe_day(&root, &config, 2022, 9, PAGE, true).unwrap();
+        assert_eq!(3, written.len());
+        assert!(skipped.is_empty());
+        assert_eq!("R 4\nU 4\n", fs::read_to_string(&example_path).unwrap());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
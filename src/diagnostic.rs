@@ -0,0 +1,106 @@
+use crate::error::{AocError, Location};
+
+/// Shows a parse error the way a compiler would, with the path, line and column it happened at, the line
+/// itself, and a caret under the column (or the whole line, if the column isn't known):
+///
+/// ```text
+/// error: Invalid Direction "X"
+///  --> ./inputs/2022/day_9.txt:3:1
+///   |
+/// 3 | X 3
+///   | ^
+/// ```
+///
+/// Returns `None` for any other error, or for a parse error that hasn't been found in its input
+pub fn render(error: &AocError, path: &str) -> Option<String> {
+    let AocError::Parse {
+        message,
+        location:
+            Location {
+                line: Some(line),
+                column,
+                text: Some(text),
+                ..
+            },
+    } = error
+    else {
+        return None;
+    };
+
+    let gutter = " ".repeat(line.to_string().len());
+    let (position, padding, underline) = match column {
+        Some(column) => (format!("{line}:{column}"), column.saturating_sub(1), 1),
+        None => (line.to_string(), 0, text.chars().count().max(1)),
+    };
+
+    Some(format!(
+        "error: {message}\n{gutter}--> {path}:{position}\n{gutter} |\n{line} | {text}\n{gutter} | {}{}",
+        " ".repeat(padding),
+        "^".repeat(underline)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use crate::error::{AocError, Location};
+
+    #[test]
+    fn render_parse_error() {
+        let error = AocError::Parse {
+            message: "Invalid x co-ordinate \"50x\"".to_string(),
+            location: Location {
+                line: Some(12),
+                column: Some(10),
+                text: Some("503,4 -> 50x,4".to_string()),
+                ..Location::default()
+            },
+        };
+
+        assert_eq!(
+            Some(
+                [
+                    "error: Invalid x co-ordinate \"50x\"",
+                    "  --> day_14.txt:12:10",
+                    "   |",
+                    "12 | 503,4 -> 50x,4",
+                    "   |          ^",
+                ]
+                .join("\n")
+            ),
+            render(&error, "day_14.txt")
+        );
+
+        let error = AocError::Parse {
+            message: "Invalid Direction \"X\"".to_string(),
+            location: Location {
+                line: Some(3),
+                text: Some("X 3".to_string()),
+                ..Location::default()
+            },
+        };
+
+        assert_eq!(
+            Some("error: Invalid Direction \"X\"\n --> -:3\n  |\n3 | X 3\n  | ^^^".to_string()),
+            render(&error, "-")
+        );
+
+        // Columns start at 1, but a column of 0 is shown at the start of the line rather than panicking
+        let error = AocError::Parse {
+            message: "Invalid Direction \"X\"".to_string(),
+            location: Location {
+                line: Some(3),
+                column: Some(0),
+                text: Some("X 3".to_string()),
+                ..Location::default()
+            },
+        };
+
+        assert_eq!(
+            Some("error: Invalid Direction \"X\"\n --> -:3:0\n  |\n3 | X 3\n  | ^".to_string()),
+            render(&error, "-")
+        );
+
+        assert_eq!(None, render(&AocError::NotImplemented, "day_6.txt"));
+    }
+}
@@ -0,0 +1,311 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use crate::BoxedError;
+
+/// Every kind of error that running a day can produce, so that each kind can be handled differently (e.g
+/// days that aren't complete are skipped, rather than failed). These are built by the macros in `macros.rs`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// An input (or another file) couldn't be read or written
+    Io(String),
+    /// The input couldn't be parsed
+    Parse { message: String, location: Location },
+    /// The input parsed fine, but a part couldn't find its answer from it
+    InvalidState(String),
+    /// The day (or one of its parts) hasn't been solved yet
+    NotImplemented,
+    /// The day took longer than the time limit
+    TimedOut(Duration),
+    /// The day was cancelled with Ctrl-C
+    Cancelled,
+    /// The day panicked. The location is where it panicked in the code, such as `src/year_2022/day_5.rs:92:20`
+    Panic {
+        message: String,
+        location: Option<String>,
+    },
+    /// Anything else, such as an invalid argument or a failed request
+    Other(String),
+}
+
+impl AocError {
+    /// The name of the kind of error, as it's written in JSON output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Io(_) => "io",
+            Self::Parse { .. } => "parse",
+            Self::InvalidState(_) => "invalid_state",
+            Self::NotImplemented => "not_implemented",
+            Self::TimedOut(_) => "timed_out",
+            Self::Cancelled => "cancelled",
+            Self::Panic { .. } => "panic",
+            Self::Other(_) => "other",
+        }
+    }
+
+    /// Turns any error that wasn't given a more specific kind into `kind`
+    fn or_kind(error: BoxedError, kind: impl FnOnce(String) -> AocError) -> BoxedError {
+        match AocError::from(error) {
+            Self::Other(message) => Box::new(kind(message)),
+            error => Box::new(error),
+        }
+    }
+
+    /// Used for errors returned while parsing, which are all parse errors unless they say otherwise
+    pub(crate) fn or_parse(error: BoxedError) -> BoxedError {
+        Self::or_kind(error, |message| Self::Parse {
+            message,
+            location: Location::default(),
+        })
+    }
+
+    /// Works out the line and column of a parse error from its offset (or the other way around), and keeps
+    /// the line's text so that it can be shown with the error. `input` is the input that was parsed
+    pub(crate) fn find_in(self, input: &str) -> Self {
+        match self {
+            Self::Parse { message, location } => Self::Parse {
+                message,
+                location: location.find_in(input),
+            },
+            error => error,
+        }
+    }
+
+    /// Used for errors returned by either part, which are all invalid states unless they say otherwise
+    pub(crate) fn or_invalid_state(error: BoxedError) -> AocError {
+        Self::from(Self::or_kind(error, Self::InvalidState))
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(message) | Self::InvalidState(message) | Self::Other(message) => {
+                write!(f, "{message}")
+            }
+            Self::Parse { message, location } => match location {
+                Location {
+                    line: Some(line),
+                    column: Some(column),
+                    ..
+                } => write!(f, "{message} (Line {line}, Column {column})"),
+                Location {
+                    line: Some(line), ..
+                } => write!(f, "{message} (Line {line})"),
+                Location {
+                    offset: Some(offset),
+                    ..
+                } => write!(f, "{message} (Byte {offset})"),
+                _ => write!(f, "{message}"),
+            },
+            Self::NotImplemented => write!(f, "Code For This Day Is Not Complete!"),
+            Self::TimedOut(timeout) => {
+                write!(f, "Timed out after {:.1} seconds", timeout.as_secs_f64())
+            }
+            Self::Cancelled => write!(f, "Cancelled with Ctrl-C"),
+            Self::Panic {
+                message,
+                location: Some(location),
+            } => write!(f, "Panicked at {location}: {message}"),
+            Self::Panic { message, .. } => write!(f, "Panicked: {message}"),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+/// Keeps an `AocError` as it is, and turns any other error into `AocError::Other`
+impl From<BoxedError> for AocError {
+    fn from(error: BoxedError) -> Self {
+        match error.downcast::<AocError>() {
+            Ok(error) => *error,
+            Err(error) => Self::Other(error.to_string()),
+        }
+    }
+}
+
+/// Where in the input a parse error happened. Lines and columns start at 1, and columns count characters
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Location {
+    /// The byte offset, from the start of the line if the line is known, or from the start of the input
+    pub offset: Option<usize>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    /// The text of the line, once it's been found in the input
+    pub text: Option<String>,
+}
+
+impl Location {
+    fn find_in(self, input: &str) -> Self {
+        let (line, offset) = match (self.line, self.offset) {
+            (Some(line), offset) => (line, offset),
+            (None, Some(offset)) => {
+                let before = &input.as_bytes()[..offset.min(input.len())];
+                let line_start = before
+                    .iter()
+                    .rposition(|byte| *byte == b'\n')
+                    .map_or(0, |index| index + 1);
+                let line = before.iter().filter(|byte| **byte == b'\n').count() + 1;
+
+                (line, Some(offset - line_start))
+            }
+            (None, None) => return self,
+        };
+
+        let text = line
+            .checked_sub(1)
+            .and_then(|index| input.split('\n').nth(index));
+
+        let column = self.column.or_else(|| {
+            let offset = offset?;
+            let text = text?;
+
+            Some(
+                text.char_indices()
+                    .take_while(|(index, _)| *index < offset)
+                    .count()
+                    + 1,
+            )
+        });
+
+        Self {
+            offset,
+            line: Some(line),
+            column,
+            text: text.map(|text| text.trim_end_matches('\r').to_string()),
+        }
+    }
+}
+
+/// The byte offset of `part` in `text`, where `part` is a slice of `text` (such as a line from `lines`, or a
+/// field from `split`). This is what `at_offset` expects, for parsers that work on a slice of their input
+pub fn offset_in(text: &str, part: &str) -> usize {
+    (part.as_ptr() as usize)
+        .checked_sub(text.as_ptr() as usize)
+        .filter(|offset| *offset <= text.len())
+        .expect("part isn't a slice of text")
+}
+
+/// Adds where a parse error happened, for parsers that hand part of their input to another parser. Errors
+/// that aren't already a different kind are turned into parse errors
+pub trait Locate<T> {
+    /// Marks the error as happening on `line` (starting at 1) of the input. An offset that the error already
+    /// has is then from the start of that line
+    fn at_line(self, line: usize) -> Result<T, BoxedError>;
+
+    /// Marks the error as happening `offset` bytes in, adding to the offset that the error already has
+    /// (which was from the start of the slice that was parsed)
+    fn at_offset(self, offset: usize) -> Result<T, BoxedError>;
+}
+
+fn locate(error: BoxedError, update: impl FnOnce(&mut Location)) -> BoxedError {
+    match AocError::from(AocError::or_parse(error)) {
+        AocError::Parse {
+            message,
+            mut location,
+        } => {
+            update(&mut location);
+            Box::new(AocError::Parse { message, location })
+        }
+        error => Box::new(error),
+    }
+}
+
+impl<T> Locate<T> for Result<T, BoxedError> {
+    fn at_line(self, line: usize) -> Result<T, BoxedError> {
+        self.map_err(|error| {
+            locate(error, |location| {
+                location.line.get_or_insert(line);
+            })
+        })
+    }
+
+    fn at_offset(self, offset: usize) -> Result<T, BoxedError> {
+        self.map_err(|error| {
+            locate(error, |location| {
+                if location.line.is_none() {
+                    location.offset = Some(location.offset.unwrap_or(0) + offset);
+                }
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{offset_in, AocError, Locate, Location};
+    use crate::macros::*;
+    use crate::BoxedError;
+
+    fn parse_number(number: &str) -> Result<u32, BoxedError> {
+        Ok(unwrap_or_return!(
+            number.parse(),
+            "Invalid Number \"{}\"",
+            number
+        ))
+    }
+
+    #[test]
+    fn error_kinds() {
+        let error = AocError::from(parse_number("12a").at_line(3).unwrap_err());
+        assert_eq!(
+            AocError::Parse {
+                message: "Invalid Number \"12a\"".to_string(),
+                location: Location {
+                    line: Some(3),
+                    ..Location::default()
+                },
+            },
+            error
+        );
+        assert_eq!("Invalid Number \"12a\" (Line 3)", error.to_string());
+
+        // A location that's already known isn't replaced
+        let error: Result<(), BoxedError> =
+            Err(err_from_str!(parse(2, 5): "Unexpected \"{}\"", '#'));
+        assert_eq!(
+            "Unexpected \"#\" (Line 2, Column 5)",
+            error.at_line(7).unwrap_err().to_string()
+        );
+
+        // Only general errors become parse errors
+        let error: Result<(), BoxedError> = Err(convert_to_err!(not_implemented));
+        assert_eq!(
+            AocError::NotImplemented,
+            AocError::from(error.at_line(1).unwrap_err())
+        );
+
+        let error = AocError::or_invalid_state(err_from_str!("No Path Found"));
+        assert_eq!(AocError::InvalidState("No Path Found".to_string()), error);
+        assert_eq!("invalid_state", error.kind());
+    }
+
+    #[test]
+    fn find_locations() {
+        let input = "498,4 -> 498,6\n503,4 -> 50x,4\n";
+        let line = input.lines().nth(1).unwrap();
+        let point = line.split(" -> ").nth(1).unwrap();
+
+        let error: Result<(), BoxedError> = Err(err_from_str!(parse(offset: 2): "Invalid x"));
+        let error = AocError::from(
+            error
+                .at_offset(offset_in(line, point))
+                .at_offset(offset_in(input, line))
+                .unwrap_err(),
+        )
+        .find_in(input);
+
+        assert_eq!("Invalid x (Line 2, Column 12)", error.to_string());
+
+        // Offsets from the start of a known line
+        let error =
+            AocError::from(parse_number("50x").at_offset(9).at_line(2).unwrap_err()).find_in(input);
+
+        let AocError::Parse { location, .. } = error else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!(Some(10), location.column);
+        assert_eq!(Some("503,4 -> 50x,4"), location.text.as_deref());
+    }
+}
@@ -0,0 +1,173 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::client::Client;
+use crate::config::Config;
+use crate::macros::*;
+use crate::BoxedError;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Fetched {
+    Downloaded,
+    /// The input was already there, so nothing was downloaded
+    Cached,
+}
+
+pub struct FetchReport {
+    pub year: u32,
+    pub day: usize,
+    pub input_path: String,
+    pub status: Result<Fetched, String>,
+}
+
+/// A single fetch, as it's written when using `--format json`
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u32,
+    day: usize,
+    status: &'a str,
+    input_path: &'a str,
+    error: Option<&'a str>,
+}
+
+impl FetchReport {
+    pub fn print(&self) {
+        match &self.status {
+            Ok(Fetched::Downloaded) => println!(
+                "Year {}, Day {}: Downloaded to \"{}\"",
+                self.year, self.day, self.input_path
+            ),
+            Ok(Fetched::Cached) => println!(
+                "Year {}, Day {}: Already downloaded to \"{}\"",
+                self.year, self.day, self.input_path
+            ),
+            Err(error) => eprintln!("Year {}, Day {}: {error}", self.year, self.day),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let (status, error) = match &self.status {
+            Ok(Fetched::Downloaded) => ("downloaded", None),
+            Ok(Fetched::Cached) => ("cached", None),
+            Err(error) => ("failed", Some(error.as_str())),
+        };
+
+        let record = Record {
+            year: self.year,
+            day: self.day,
+            status,
+            input_path: &self.input_path,
+            error,
+        };
+
+        serde_json::to_string(&record).unwrap()
+    }
+}
+
+/// Downloads a day's input to where `Year::get_input` expects it. An input that's already there
+/// is never downloaded again, but an empty file (as left by `Year::get_input`) is replaced
+pub(crate) fn fetch_input(
+    config: &Config,
+    client: &Client,
+    year: u32,
+    day_num: usize,
+) -> FetchReport {
+    let path = config.get_input_path(year, day_num);
+
+    FetchReport {
+        year,
+        day: day_num,
+        input_path: path.display().to_string(),
+        status: download(config, client, year, day_num, &path).map_err(|e| e.to_string()),
+    }
+}
+
+fn download(
+    config: &Config,
+    client: &Client,
+    year: u32,
+    day_num: usize,
+    path: &Path,
+) -> Result<Fetched, BoxedError> {
+    if fs::read_to_string(path).is_ok_and(|input| !input.trim().is_empty()) {
+        return Ok(Fetched::Cached);
+    }
+
+    if config.read_only {
+        return_err!(
+            "Can't download the input to \"{}\" in read-only mode",
+            path.display()
+        );
+    }
+
+    // Stored exactly as it was served, since pasting an input by hand is what goes wrong
+    let input = client.get_input(year, day_num)?;
+
+    if let Some(folder) = path.parent() {
+        unwrap_or_return!(
+            fs::create_dir_all(folder),
+            error: e,
+            io: "Failed to create folder \"{}\"\nReason: {}",
+            folder.display(),
+            e
+        );
+    }
+
+    unwrap_or_return!(
+        fs::write(path, input),
+        error: e,
+        io: "Failed to write input to \"{}\"\nReason: {}",
+        path.display(),
+        e
+    );
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use super::{fetch_input, Fetched};
+    use crate::client::stub::serve_once;
+    use crate::client::Client;
+    use crate::config::Config;
+
+    #[test]
+    fn fetch_once() {
+        let input_root = env::temp_dir().join(format!("aoc_fetch_{}", std::process::id()));
+        let (base_url, handle) = serve_once("200 OK", "498,4 -> 498,6 -> 496,6\r\n");
+
+        let config = Config {
+            input_root: input_root.clone(),
+            session: Some("abc123".to_string()),
+            base_url,
+            ..Default::default()
+        };
+        let client = Client::new(&config).unwrap();
+
+        let report = fetch_input(&config, &client, 2022, 14);
+        handle.join().unwrap();
+
+        assert_eq!(Ok(Fetched::Downloaded), report.status);
+        assert_eq!(
+            "498,4 -> 498,6 -> 496,6\r\n",
+            fs::read_to_string(config.get_input_path(2022, 14)).unwrap()
+        );
+
+        // The stub server is gone, so this would fail if it tried to download the input again
+        let report = fetch_input(&config, &client, 2022, 14);
+        assert_eq!(Ok(Fetched::Cached), report.status);
+
+        let read_only = Config {
+            read_only: true,
+            ..config
+        };
+        assert!(fetch_input(&read_only, &client, 2022, 15).status.is_err());
+
+        fs::remove_dir_all(input_root).unwrap();
+    }
+}
@@ -0,0 +1,339 @@
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cli::EXIT_INTERRUPTED;
+use crate::error::AocError;
+use crate::macros::*;
+use crate::{Answers, BoxedError, DayReturnType};
+
+/// How often a running day is checked on, to see if it's been cancelled or has run out of time
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// How long a day that's been told to stop is given to do so, before it's left running in the background
+const STOP_GRACE_PERIOD: Duration = Duration::from_millis(200);
+
+/// Days run on their own thread, which is given as much stack as the main thread usually has
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+thread_local! {
+    /// Whether this thread is inside `catch_panic`, in which case its panics are recorded instead of printed
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// The message and location of the last panic that was caught on this thread
+    static LAST_PANIC: RefCell<Option<(String, Option<String>)>> = const { RefCell::new(None) };
+    /// Set when the day running on this thread should stop, see `check_cancelled`
+    static CANCELLED: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+    /// Where the day running on this thread keeps the answers it has so far, see `save_progress`
+    static PROGRESS: RefCell<Option<Arc<Mutex<Option<Answers>>>>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+static INSTALL_CTRL_C: Once = Once::new();
+
+/// The flag of the day that's running, which Ctrl-C sets. Ctrl-C exits as usual when no day is running
+static RUNNING: Mutex<Option<Arc<AtomicBool>>> = Mutex::new(None);
+
+/// Called before Ctrl-C exits, see `set_interrupt_cleanup`
+static CLEANUP: Mutex<Option<fn()>> = Mutex::new(None);
+
+fn get_message(info: &PanicHookInfo) -> String {
+    if let Some(message) = info.payload().downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    match info.payload().downcast_ref::<String>() {
+        Some(message) => message.clone(),
+        None => "Unknown panic".to_string(),
+    }
+}
+
+/// Wraps the current panic hook so that panics inside `catch_panic` are recorded rather than printed. This is
+/// installed the first time a day is run, so that it wraps any hook set up before then (such as the one that
+/// restores the terminal for the interactive menu), which is left to handle every other panic
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.get() {
+                return previous(info);
+            }
+
+            let location = info.location().map(ToString::to_string);
+            LAST_PANIC.set(Some((get_message(info), location)));
+        }));
+    });
+}
+
+/// Runs `function`, turning a panic into an `AocError::Panic` with the panic's message and location, so that
+/// one broken day doesn't bring everything else down with it
+pub(crate) fn catch_panic<T>(
+    function: impl FnOnce() -> Result<T, BoxedError>,
+) -> Result<T, BoxedError> {
+    install_hook();
+
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(function));
+    CATCHING.set(was_catching);
+
+    result.unwrap_or_else(|_| {
+        let (message, location) = LAST_PANIC
+            .take()
+            .unwrap_or_else(|| ("Unknown panic".to_string(), None));
+
+        Err(Box::new(AocError::Panic { message, location }))
+    })
+}
+
+/// Returns `AocError::Cancelled` once the day has been cancelled with Ctrl-C, or has run out of time. A day
+/// can't be stopped from the outside, so long running loops should check this every so often
+pub(crate) fn check_cancelled() -> Result<(), BoxedError> {
+    let cancelled = CANCELLED.with_borrow(|cancelled| {
+        cancelled
+            .as_ref()
+            .is_some_and(|cancelled| cancelled.load(Ordering::Relaxed))
+    });
+
+    if cancelled {
+        return_err!(cancelled);
+    }
+
+    Ok(())
+}
+
+/// Keeps the answers that the day running on this thread has so far, so that they can still be reported if
+/// the day is cancelled or runs out of time before it finishes
+pub(crate) fn save_progress(answers: &Answers) {
+    PROGRESS.with_borrow(|progress| {
+        if let Some(progress) = progress {
+            *progress.lock().unwrap() = Some(answers.clone());
+        }
+    });
+}
+
+/// Sets what to do before Ctrl-C exits, when it's pressed while no day is running (such as between the days
+/// of `all`). The interactive menu uses this to restore the terminal
+pub(crate) fn set_interrupt_cleanup(cleanup: Option<fn()>) {
+    *CLEANUP.lock().unwrap() = cleanup;
+}
+
+fn install_ctrl_c_handler() {
+    INSTALL_CTRL_C.call_once(|| {
+        let result = ctrlc::set_handler(|| match RUNNING.lock().unwrap().as_ref() {
+            Some(cancelled) => cancelled.store(true, Ordering::Relaxed),
+            None => {
+                if let Some(cleanup) = *CLEANUP.lock().unwrap() {
+                    cleanup();
+                }
+
+                process::exit(EXIT_INTERRUPTED.into());
+            }
+        });
+
+        if let Err(e) = result {
+            eprintln!("Warning: Ctrl-C won't cancel days\nReason: {e}");
+        }
+    });
+}
+
+/// Runs a day on its own thread (catching any panics), and waits for it to finish. Pressing Ctrl-C, or taking
+/// longer than `timeout`, stops waiting and returns the answers saved with `save_progress` (with the error in
+/// place of the missing part), or just the error if there aren't any. The day is told to stop (see
+/// `check_cancelled`), but it's left to finish in the background if it doesn't
+pub(crate) fn run_isolated(
+    function: fn(&str) -> DayReturnType,
+    input: &str,
+    timeout: Option<Duration>,
+) -> DayReturnType {
+    install_ctrl_c_handler();
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let progress = Arc::new(Mutex::new(None));
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+
+    let worker = {
+        let cancelled = cancelled.clone();
+        let progress = progress.clone();
+
+        thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn(move || {
+                CANCELLED.set(Some(cancelled));
+                PROGRESS.set(Some(progress));
+
+                // Boxed errors can't be sent between threads, but an `AocError` can
+                let result = catch_panic(|| function(&input)).map_err(AocError::from);
+                sender.send(result).ok();
+            })
+    };
+
+    if let Err(e) = worker {
+        return_err!("Failed to start the day\nReason: {}", e);
+    }
+
+    *RUNNING.lock().unwrap() = Some(cancelled.clone());
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    let error = loop {
+        match receiver.recv_timeout(POLL_INTERVAL) {
+            Ok(result) => {
+                *RUNNING.lock().unwrap() = None;
+                return result.map_err(BoxedError::from);
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                break AocError::Other("The day stopped without an answer".to_string())
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+
+        if cancelled.load(Ordering::Relaxed) {
+            break AocError::Cancelled;
+        }
+
+        if let (Some(deadline), Some(timeout)) = (deadline, timeout) {
+            if Instant::now() >= deadline {
+                cancelled.store(true, Ordering::Relaxed);
+                break AocError::TimedOut(timeout);
+            }
+        }
+    };
+
+    *RUNNING.lock().unwrap() = None;
+
+    // A day that checks `check_cancelled` stops straight away, but any other day carries on using up a core
+    // until it finishes, which could slow down the days after it
+    if let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(STOP_GRACE_PERIOD) {
+        eprintln!("Warning: The day is still running in the background, as it didn't stop when it was told to");
+    }
+
+    let answers = progress.lock().unwrap().take();
+
+    match answers {
+        Some(answers) => Ok(Answers {
+            part_2: Err(error),
+            ..answers
+        }),
+        None => Err(Box::new(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{catch_panic, check_cancelled, run_isolated};
+    use crate::error::AocError;
+    use crate::{Answers, BoxedError, DayReturnType, Solution, Timings};
+
+    #[test]
+    fn catch_panics() {
+        let result: Result<u32, _> = catch_panic(|| Ok(Vec::<u32>::new()[0]));
+        let error = AocError::from(result.unwrap_err());
+
+        let AocError::Panic { message, location } = &error else {
+            panic!("Expected a panic, not {error:?}");
+        };
+        assert_eq!(
+            "index out of bounds: the len is 0 but the index is 0",
+            message
+        );
+        assert!(location.as_ref().unwrap().starts_with("src/isolate.rs:"));
+        assert_eq!("panic", error.kind());
+
+        let result: Result<(), _> = catch_panic(|| panic!("Column {} is empty", 3));
+        assert!(matches!(
+            AocError::from(result.unwrap_err()),
+            AocError::Panic { message, .. } if message == "Column 3 is empty"
+        ));
+
+        assert_eq!(7, catch_panic(|| Ok(7)).unwrap());
+    }
+
+    fn count_forever(_input: &str) -> DayReturnType {
+        loop {
+            check_cancelled()?;
+        }
+    }
+
+    /// Never checks if it's been cancelled, so it's left running in the background
+    fn sleeps(_input: &str) -> DayReturnType {
+        thread::sleep(Duration::from_secs(1));
+        count_forever("")
+    }
+
+    fn panics(input: &str) -> DayReturnType {
+        let number: u32 = input.parse().unwrap();
+
+        Ok(Answers {
+            part_1: Ok(number.to_string()),
+            part_2: Ok(String::new()),
+            timings: Timings::default(),
+        })
+    }
+
+    /// Part 1 is fine, but Part 2 never finishes
+    struct Stuck;
+
+    impl Solution for Stuck {
+        type Input = ();
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(_input: &str) -> Result<(), BoxedError> {
+            Ok(())
+        }
+
+        fn part_1(_input: &()) -> Result<u32, BoxedError> {
+            Ok(1)
+        }
+
+        fn part_2(_input: &()) -> Result<u32, BoxedError> {
+            loop {
+                check_cancelled()?;
+            }
+        }
+    }
+
+    #[test]
+    fn run_days() {
+        let timeout = Some(Duration::from_millis(50));
+
+        let result = run_isolated(count_forever, "", timeout);
+        assert_eq!(
+            AocError::TimedOut(Duration::from_millis(50)),
+            AocError::from(result.unwrap_err())
+        );
+
+        let result = run_isolated(sleeps, "", timeout);
+        assert_eq!(
+            AocError::TimedOut(Duration::from_millis(50)),
+            AocError::from(result.unwrap_err())
+        );
+
+        let result = run_isolated(panics, "x", timeout);
+        assert!(matches!(
+            AocError::from(result.unwrap_err()),
+            AocError::Panic { location: Some(location), .. } if location.starts_with("src/isolate.rs:")
+        ));
+
+        assert_eq!(
+            "7",
+            run_isolated(panics, "7", None).unwrap().part_1.unwrap()
+        );
+        assert!(check_cancelled().is_ok());
+
+        let answers = run_isolated(Stuck::execute, "", timeout).unwrap();
+        assert_eq!(Ok("1".to_string()), answers.part_1);
+        assert_eq!(
+            Err(AocError::TimedOut(Duration::from_millis(50))),
+            answers.part_2
+        );
+    }
+}
@@ -1,34 +1,168 @@
 use std::error::Error;
+use std::fmt::Display;
 use std::fs;
 use std::io;
 use std::io::Read;
 use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use clearscreen::clear;
 
+pub mod answers;
+pub mod bench;
+pub mod calendar;
+pub mod cli;
+mod client;
+pub mod config;
+pub mod describe;
+pub mod diagnostic;
+pub mod error;
+pub mod fetch;
+mod isolate;
 pub mod macros;
+pub mod normalize;
+pub mod report;
+pub mod scaffold;
+pub mod submit;
+pub mod tui;
+mod watch;
 pub mod year_2022;
 
 pub type BoxedError = Box<dyn Error>;
-pub type DayReturnType = Result<(String, String), BoxedError>;
+pub type DayReturnType = Result<Answers, BoxedError>;
 
-extern crate simple_error;
+/// The input path that reads the input from stdin instead of from a file
+pub const STDIN_INPUT: &str = "-";
+
+/// Selects the latest puzzle, instead of a specific year or day
+pub const TODAY: &str = "today";
+
+use answers::{AnswerStore, ExpectedAnswers};
+use config::Config;
+use error::AocError;
+use isolate::{catch_panic, check_cancelled, run_isolated, save_progress};
 use macros::*;
+use normalize::{normalize, Normalized};
+use report::{DayReport, Status};
+
+/// How long each phase of a day took to run, in nanoseconds
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Timings {
+    pub parse: u128,
+    pub part_1: u128,
+    pub part_2: u128,
+}
+
+impl Timings {
+    pub fn total(&self) -> u128 {
+        self.parse + self.part_1 + self.part_2
+    }
+}
+
+/// A single part's answer, or the reason it doesn't have one
+pub type PartResult = Result<String, AocError>;
+
+/// Each part's answer is kept separately, so that one part failing doesn't hide the other part's answer
+#[derive(Clone, Debug)]
+pub struct Answers {
+    pub part_1: PartResult,
+    pub part_2: PartResult,
+    pub timings: Timings,
+}
+
+impl Answers {
+    /// The error from the first part that failed. Parts that aren't complete yet haven't failed
+    pub fn get_error(&self) -> Option<&AocError> {
+        [&self.part_1, &self.part_2]
+            .into_iter()
+            .find_map(|result| match result {
+                Err(AocError::NotImplemented) | Ok(_) => None,
+                Err(error) => Some(error),
+            })
+    }
+
+    /// Whether neither part is complete yet
+    pub fn is_not_implemented(&self) -> bool {
+        [&self.part_1, &self.part_2]
+            .iter()
+            .all(|result| matches!(result, Err(AocError::NotImplemented)))
+    }
+}
+
+/// Runs a single part, so that it can fail (or panic) without taking the other part down with it
+fn run_part<T: Display>(part: impl FnOnce() -> Result<T, BoxedError>) -> PartResult {
+    catch_panic(part)
+        .map(|answer| answer.to_string())
+        .map_err(AocError::or_invalid_state)
+}
+
+/// A single day's solution. The input is parsed once into `Input`, which is then shared by both parts
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Whether trailing whitespace is removed from each line when the input is normalized (see
+    /// `normalize::normalize`). Days that depend on the exact layout of their input can turn this off
+    const TRIM_WHITESPACE: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input, BoxedError>;
+    fn part_1(input: &Self::Input) -> Result<Self::Answer1, BoxedError>;
+    fn part_2(input: &Self::Input) -> Result<Self::Answer2, BoxedError>;
+
+    /// Runs every phase on the input, timing each one separately. If the input can't be parsed the whole day
+    /// fails, but otherwise each part gets its own result. Errors from `parse` are parse errors, and errors
+    /// from either part are invalid states, unless they were given another kind
+    fn execute(input: &str) -> DayReturnType {
+        let mut timings = Timings::default();
+
+        let start_time = Instant::now();
+        let parsed = Self::parse(input).map_err(AocError::or_parse)?;
+        timings.parse = start_time.elapsed().as_nanos();
+
+        let start_time = Instant::now();
+        let part_1 = run_part(|| Self::part_1(&parsed));
+        timings.part_1 = start_time.elapsed().as_nanos();
+
+        let mut answers = Answers {
+            part_1,
+            part_2: Err(AocError::Cancelled),
+            timings,
+        };
+
+        // If Part 2 is cancelled or runs out of time, Part 1's answer is still reported
+        save_progress(&answers);
+        if let Err(e) = check_cancelled() {
+            answers.part_2 = Err(e.into());
+            return Ok(answers);
+        }
+
+        let start_time = Instant::now();
+        answers.part_2 = run_part(|| Self::part_2(&parsed));
+        answers.timings.part_2 = start_time.elapsed().as_nanos();
+
+        Ok(answers)
+    }
+}
 
 struct Year {
     year: u32,
-    days: Vec<Day>,
+    days: Vec<&'static Day>,
 }
 
 impl Year {
     fn display_days(&self) {
-        for (i, day) in self.days.iter().enumerate() {
-            println!("Day {}: {}", i + 1, day.name);
+        for day in self.days.iter() {
+            println!("Day {}: {}", day.day, day.name);
         }
     }
 
     fn get_day(&self, input: &str) -> Result<&Day, BoxedError> {
+        if input.trim().eq_ignore_ascii_case(TODAY) {
+            return self.get_today(calendar::unix_time());
+        }
+
         let input = input.replace(':', "");
         let input = if input.len() > 3 && &input.to_lowercase()[..3] == "day" {
             unwrap_option_or_return!(input.split(' ').nth(1), "Invalid Day \"{}\"", input)
@@ -36,10 +170,10 @@ impl Year {
             &input
         };
 
-        let parsed_input: u32 = unwrap_or_return!(input.parse(), "Invalid Day \"{}\"", input);
+        let parsed_input: usize = unwrap_or_return!(input.parse(), "Invalid Day \"{}\"", input);
 
-        for (i, day) in self.days.iter().enumerate() {
-            if parsed_input == (i + 1) as u32 {
+        for day in self.days.iter() {
+            if parsed_input == day.day {
                 return Ok(day);
             }
         }
@@ -47,71 +181,72 @@ impl Year {
         return_err!("Couldn't Find Day {}", parsed_input);
     }
 
+    /// Collects every day that was registered with `register_day!`, grouped by year and sorted by day
     fn create_years() -> Vec<Self> {
-        vec![Self {
-            year: 2022,
-            days: vec![
-                Day {
-                    name: "Calorie Counting".to_string(),
-                    function: crate::year_2022::day_1::execute,
-                },
-                Day {
-                    name: "Rock Paper Scissors".to_string(),
-                    function: crate::year_2022::day_2::execute,
-                },
-                Day {
-                    name: "Rucksack Reorganization".to_string(),
-                    function: crate::year_2022::day_3::execute,
-                },
-                Day {
-                    name: "Camp Cleanup".to_string(),
-                    function: crate::year_2022::day_4::execute,
-                },
-                Day {
-                    name: "Supply Stacks".to_string(),
-                    function: crate::year_2022::day_5::execute,
-                },
-                Day {
-                    name: "Tuning Trouble".to_string(),
-                    function: crate::year_2022::day_6::execute,
-                },
-                Day {
-                    name: "No Space Left On Device".to_string(),
-                    function: crate::year_2022::day_7::execute,
-                },
-                Day {
-                    name: "Treetop Tree House".to_string(),
-                    function: crate::year_2022::day_8::execute,
-                },
-                Day {
-                    name: "Rope Bridge".to_string(),
-                    function: crate::year_2022::day_9::execute,
-                },
-                Day {
-                    name: "Cathode-Ray Tube".to_string(),
-                    function: crate::year_2022::day_10::execute,
-                },
-                Day {
-                    name: "Monkey in the Middle".to_string(),
-                    function: crate::year_2022::day_11::execute,
-                },
-                Day {
-                    name: "Hill Climbing Algorithm".to_string(),
-                    function: crate::year_2022::day_12::execute,
-                },
-                Day {
-                    name: "Distress Signal".to_string(),
-                    function: crate::year_2022::day_13::execute,
-                },
-                Day {
-                    name: "Regolith Reservoir".to_string(),
-                    function: crate::year_2022::day_14::execute,
-                },
-            ],
-        }]
+        let mut years: Vec<Self> = Vec::new();
+
+        for day in inventory::iter::<Day> {
+            match years.iter_mut().find(|year| year.year == day.year) {
+                Some(year) => year.days.push(day),
+                None => years.push(Self {
+                    year: day.year,
+                    days: vec![day],
+                }),
+            }
+        }
+
+        years.sort_by_key(|year| year.year);
+        for year in years.iter_mut() {
+            year.days.sort_by_key(|day| day.day);
+        }
+
+        years
+    }
+
+    /// Today's puzzle, if this year's event is on. Otherwise, the latest day in the year
+    fn get_today(&self, unix_time: u64) -> Result<&Day, BoxedError> {
+        match calendar::get_current_puzzle(unix_time) {
+            Some((year, day_num)) if year == self.year => {
+                let day = self.days.iter().find(|day| day.day == day_num);
+
+                Ok(unwrap_option_or_return!(
+                    day,
+                    "Year {}, Day {} hasn't been added yet! Add it with \"advent_of_code new {} {} <TITLE>\"",
+                    year,
+                    day_num,
+                    year,
+                    day_num
+                ))
+            }
+            _ => Ok(unwrap_option_or_return!(
+                self.days.last(),
+                "Year {} doesn't have any days yet!",
+                self.year
+            )),
+        }
+    }
+
+    /// The year of the event that's on, or the latest year if there isn't one
+    fn get_current_year(years: &[Year], unix_time: u64) -> Result<&Year, BoxedError> {
+        if let Some((current_year, _)) = calendar::get_current_puzzle(unix_time) {
+            return Ok(unwrap_option_or_return!(
+                years.iter().find(|year| year.year == current_year),
+                "Year {} hasn't been added yet!",
+                current_year
+            ));
+        }
+
+        Ok(unwrap_option_or_return!(
+            years.last(),
+            "There aren't any years yet!"
+        ))
     }
 
     fn get_year<'a>(years: &'a [Year], input: &str) -> Result<&'a Year, BoxedError> {
+        if input.trim().eq_ignore_ascii_case(TODAY) {
+            return Self::get_current_year(years, calendar::unix_time());
+        }
+
         let parsed_input: u32 =
             unwrap_or_return!(input.parse(), "\"{}\" Isn't A Valid Year!", input);
 
@@ -130,93 +265,385 @@ impl Year {
         }
     }
 
-    fn get_input(&self, day: &Day) -> Result<String, BoxedError> {
-        let mut path = String::new();
-        let mut day_num = 0;
+    /// The path of a day's input, or of one of its named inputs (such as a teammate's input)
+    fn get_input_path(&self, day_num: usize, name: Option<&str>, config: &Config) -> PathBuf {
+        match name {
+            Some(name) => config.get_named_input_path(self.year, day_num, name),
+            None => config.get_input_path(self.year, day_num),
+        }
+    }
 
-        for (i, day_iter) in self.days.iter().enumerate() {
-            if day == day_iter {
-                day_num = i + 1;
-                path = format!("./inputs/{}/day_{}.txt", self.year, day_num);
-                break;
+    /// Reads a day's input, or one of its named inputs. Unless the config is read-only, a missing input file
+    /// (and its folder) is created so that the input can be pasted into it
+    fn get_input(
+        &self,
+        day: &Day,
+        name: Option<&str>,
+        config: &Config,
+    ) -> Result<String, BoxedError> {
+        let day_num = day.day;
+        let path = self.get_input_path(day_num, name, config);
+
+        if !path.exists() {
+            if config.read_only {
+                return_err!(
+                    io: "Failed to load input for Year {}, Day {} (Path: \"{}\")\nReason: File doesn't exist",
+                    self.year,
+                    day_num,
+                    path.display()
+                );
             }
-        }
 
-        if path.is_empty() {
-            return_err!("Couldn't Find Day \"{}\" In Year {}", day.name, self.year);
-        };
+            Self::create_input(&path)?;
+        }
 
-        let folder = path.split("/day_").next().unwrap();
-        unwrap_or_return!(
-            fs::create_dir_all(folder),
+        let contents = unwrap_or_return!(
+            fs::read_to_string(&path),
             error: e,
-            "Failed to create folder \"{}\"\nReason: {}",
-            path,
+            io: "Failed to open input for Year {}, Day {} (Path: \"{}\")\nReason: {}",
+            self.year,
+            day_num,
+            path.display(),
             e
         );
 
-        if !std::path::Path::new(&path).exists() {
+        if contents.trim().is_empty() {
+            return_err!(
+                io: "Failed to load input for Year {}, Day {} (Path: \"{}\")\nReason: File is empty. Please put your input for Day {} into this file",
+                self.year,
+                day_num,
+                path.display(),
+                day_num,
+            );
+        }
+
+        Ok(contents)
+    }
+
+    fn create_input(path: &Path) -> Result<(), BoxedError> {
+        if let Some(folder) = path.parent() {
             unwrap_or_return!(
-                fs::OpenOptions::new()
-                    .write(true)
-                    .create_new(true)
-                    .open(&path),
+                fs::create_dir_all(folder),
                 error: e,
-                "Failed to create file \"{}\"\nReason: {}",
-                path,
+                io: "Failed to create folder \"{}\"\nReason: {}",
+                folder.display(),
                 e
             );
         }
 
-        let contents = unwrap_or_return!(
-            fs::read_to_string(&path),
+        unwrap_or_return!(
+            fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(path),
             error: e,
-            "Failed to open input for Year {}, Day {} (Path: \"{}\")\nReason: {}",
-            self.year,
-            day_num,
-            path,
+            io: "Failed to create file \"{}\"\nReason: {}",
+            path.display(),
             e
         );
 
-        if contents.trim().is_empty() {
+        Ok(())
+    }
+
+    /// Reads a day's input without creating anything, returning `None` if it's missing or empty
+    fn read_input(&self, day_num: usize, config: &Config) -> Result<Option<String>, BoxedError> {
+        let path = config.get_input_path(self.year, day_num);
+
+        match fs::read_to_string(&path) {
+            Ok(input) if input.trim().is_empty() => Ok(None),
+            Ok(input) => Ok(Some(input)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => return_err!(
+                io: "Failed to open input (Path: \"{}\")\nReason: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+
+    /// Lists the names of a day's named inputs, in alphabetical order
+    fn get_input_names(&self, day_num: usize, config: &Config) -> Result<Vec<String>, BoxedError> {
+        let folder = config.get_named_inputs_folder(self.year, day_num);
+        let extension = config.get_input_path(self.year, day_num);
+        let extension = extension.extension();
+
+        let entries = match fs::read_dir(&folder) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return_err!(
+                io: "Failed to open named inputs (Path: \"{}\")\nReason: {}",
+                folder.display(),
+                e
+            ),
+        };
+
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && path.extension() == extension)
+            .filter_map(|path| Some(path.file_stem()?.to_string_lossy().to_string()))
+            .collect();
+
+        names.sort();
+        Ok(names)
+    }
+
+    /// Loads the known answers for this year. A broken answers file shouldn't stop the days from running,
+    /// so it's reported and then treated as if there were no known answers
+    fn load_answers(&self, config: &Config) -> AnswerStore {
+        unwrap_or_else!(AnswerStore::load(&config.get_answers_path(self.year)), error: e, {
+            eprintln!("Warning: {e}");
+            AnswerStore::default()
+        })
+    }
+
+    /// Loads the known answers for a single input, such as an example or a named input. Like `load_answers`,
+    /// a broken file is only reported
+    fn load_expected_answers(path: &Path) -> ExpectedAnswers {
+        unwrap_or_else!(ExpectedAnswers::load(path), error: e, {
+            eprintln!("Warning: {e}");
+            ExpectedAnswers::default()
+        })
+    }
+
+    /// The known answers for a day's input. Each named input has its own, next to it (e.g "day_9/alice.toml")
+    fn get_expected_answers(
+        &self,
+        day_num: usize,
+        name: Option<&str>,
+        config: &Config,
+    ) -> ExpectedAnswers {
+        match name {
+            Some(name) => Self::load_expected_answers(
+                &config.get_named_answers_path(self.year, day_num, name),
+            ),
+            None => self.load_answers(config).get(day_num),
+        }
+    }
+
+    fn create_report(
+        &self,
+        day: &Day,
+        (status, input_changes): (Status, Vec<String>),
+        expected: ExpectedAnswers,
+        input_path: &Path,
+    ) -> DayReport {
+        DayReport {
+            year: self.year,
+            day: day.day,
+            name: day.name.to_string(),
+            input_path: input_path.display().to_string(),
+            status,
+            expected,
+            input_changes,
+        }
+    }
+
+    /// Runs a single day against its input, or one of its named inputs, creating the input file if it
+    /// doesn't exist yet
+    fn report_day(&self, day: &Day, name: Option<&str>, config: &Config) -> DayReport {
+        let status = match self.get_input(day, name, config) {
+            Ok(input) => day.report(&input, config),
+            Err(e) => (Status::Failed(e.into()), Vec::new()),
+        };
+
+        let day_num = day.day;
+        let expected = self.get_expected_answers(day_num, name, config);
+        let path = self.get_input_path(day_num, name, config);
+
+        self.create_report(day, status, expected, &path)
+    }
+
+    /// Runs a single day against its input and every one of its named inputs, so that the answers can be
+    /// compared. Nothing is created, and inputs that are missing or empty are left out
+    fn compare_inputs(&self, day: &Day, config: &Config) -> Result<Vec<DayReport>, BoxedError> {
+        let day_num = day.day;
+        let mut reports = Vec::new();
+
+        if let Some(input) = self.read_input(day_num, config)? {
+            let expected = self.get_expected_answers(day_num, None, config);
+            let path = self.get_input_path(day_num, None, config);
+
+            reports.push(self.create_report(day, day.report(&input, config), expected, &path));
+        }
+
+        for name in self.get_input_names(day_num, config)? {
+            let path = self.get_input_path(day_num, Some(&name), config);
+
+            let status = match read_input_from(&path.display().to_string()) {
+                Ok(input) => day.report(&input, config),
+                Err(e) => (Status::Failed(e.into()), Vec::new()),
+            };
+
+            let expected = self.get_expected_answers(day_num, Some(&name), config);
+            reports.push(self.create_report(day, status, expected, &path));
+        }
+
+        if reports.is_empty() {
             return_err!(
-                "Failed to load input for Year {}, Day {} (Path: \"{}\")\nReason: File is empty. Please put your input for Day {} into this file",
+                "Year {}, Day {} has no inputs to compare (Paths: \"{}\" and \"{}\")",
                 self.year,
                 day_num,
-                path,
-                day_num,
+                config.get_input_path(self.year, day_num).display(),
+                config.get_named_inputs_folder(self.year, day_num).display()
             );
         }
 
-        Ok(contents)
+        Ok(reports)
+    }
+
+    /// Runs a single day against an input from somewhere other than the usual input path, such as someone
+    /// else's input. The known answers only apply to our own inputs, so none are expected
+    fn report_day_with_input(&self, day: &Day, path: &str, config: &Config) -> DayReport {
+        let status = match read_input_from(path) {
+            Ok(input) => day.report(&input, config),
+            Err(e) => (Status::Failed(e.into()), Vec::new()),
+        };
+
+        self.create_report(day, status, ExpectedAnswers::default(), Path::new(path))
+    }
+
+    /// Runs a single day against one of its examples, checking the answers against the example's own known
+    /// answers (if it has any). Examples are never created, since they have to come from the puzzle
+    fn report_example(&self, day: &Day, index: usize, config: &Config) -> DayReport {
+        let day_num = day.day;
+        let path = config.get_example_path(self.year, day_num, index);
+
+        let status = if path.exists() {
+            match read_input_from(&path.display().to_string()) {
+                Ok(input) => day.report(&input, config),
+                Err(e) => (Status::Failed(e.into()), Vec::new()),
+            }
+        } else {
+            let error = AocError::Io(format!(
+                "Year {}, Day {} has no example {} (Path: \"{}\")\nReason: File doesn't exist. Use \"describe\" to save the examples from the puzzle, or paste one into this file",
+                self.year,
+                day_num,
+                index,
+                path.display()
+            ));
+
+            (Status::Failed(error), Vec::new())
+        };
+
+        let expected = Self::load_expected_answers(
+            &config.get_example_answers_path(self.year, day_num, index),
+        );
+
+        self.create_report(day, status, expected, &path)
+    }
+
+    /// Runs every day in the year without prompting for or creating any input files.
+    /// Days that have no input, or that aren't complete yet, are reported as skipped
+    fn report_days(&self, config: &Config) -> Vec<DayReport> {
+        let mut reports = Vec::new();
+        let answers = self.load_answers(config);
+
+        for day in self.days.iter() {
+            let status = match self.read_input(day.day, config) {
+                Ok(Some(input)) => day.report(&input, config),
+                Ok(None) => (Status::Skipped("No Input".to_string()), Vec::new()),
+                Err(e) => (Status::Failed(e.into()), Vec::new()),
+            };
+
+            let path = config.get_input_path(self.year, day.day);
+            reports.push(self.create_report(day, status, answers.get(day.day), &path));
+        }
+
+        reports
     }
 }
 
+/// A single day's solution, as registered by `register_day!` in the day's module
 struct Day {
-    name: String,
+    year: u32,
+    day: usize,
+    name: &'static str,
     function: fn(&str) -> DayReturnType,
+    trim_whitespace: bool,
 }
 
+inventory::collect!(Day);
+
 impl Day {
-    fn execute(&self, input: &str) -> DayReturnType {
-        (self.function)(input)
+    /// Runs the day on its own thread, turning a panic, Ctrl-C, or running out of time into an error (see
+    /// `isolate::run_isolated`)
+    fn execute(&self, input: &str, config: &Config) -> DayReturnType {
+        run_isolated(self.function, input, config.timeout)
+    }
+
+    /// Normalizes the input, leaving the whitespace on each line alone if the day needs it
+    fn prepare_input(&self, input: &str) -> Normalized {
+        normalize(input, self.trim_whitespace)
+    }
+
+    /// Runs the day, returning how it went along with whatever was changed by normalizing the input
+    fn report(&self, input: &str, config: &Config) -> (Status, Vec<String>) {
+        let Normalized { input, changes } = self.prepare_input(input);
+
+        let status = match self.execute(&input, config) {
+            Ok(answers) if answers.is_not_implemented() => {
+                Status::Skipped("Not Complete".to_string())
+            }
+            Ok(answers) => Status::Completed(answers),
+            Err(e) => match AocError::from(e) {
+                AocError::NotImplemented => Status::Skipped("Not Complete".to_string()),
+                error => Status::Failed(error.find_in(&input)),
+            },
+        };
+
+        (status, changes)
     }
 }
 
 impl core::cmp::PartialEq for Day {
     fn eq(&self, other: &Self) -> bool {
-        self.name == other.name
+        self.year == other.year && self.day == other.day
     }
 }
 
-pub fn get_input(prompt: &str) -> String {
+/// Reads an input from `path`, or from stdin if the path is `STDIN_INPUT`
+fn read_input_from(path: &str) -> Result<String, BoxedError> {
+    let mut contents = String::new();
+
+    if path == STDIN_INPUT {
+        unwrap_or_return!(
+            io::stdin().read_to_string(&mut contents),
+            error: e,
+            io: "Failed to read input from stdin\nReason: {}",
+            e
+        );
+    } else {
+        contents = unwrap_or_return!(
+            fs::read_to_string(path),
+            error: e,
+            io: "Failed to open input (Path: \"{}\")\nReason: {}",
+            path,
+            e
+        );
+    }
+
+    if contents.trim().is_empty() {
+        return_err!(
+            io: "Failed to load input (Path: \"{}\")\nReason: Input is empty",
+            path
+        );
+    }
+
+    Ok(contents)
+}
+
+/// Returns `None` once stdin has been closed (e.g when the menus are driven by a script that's finished)
+pub fn get_input(prompt: &str) -> Option<String> {
     print!("{prompt}");
     io::stdout().flush().unwrap();
 
     let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
+    if io::stdin().read_line(&mut input).unwrap() == 0 {
+        return None;
+    }
 
-    input.trim().to_string()
+    Some(input.trim().to_string())
 }
 
 pub fn pause(prompt: &str) {
@@ -229,7 +656,8 @@ pub fn pause(prompt: &str) {
     let _ = stdin.read(&mut [0u8]).unwrap();
 }
 
-pub fn select_day() -> Result<(String, String, u128), BoxedError> {
+/// Asks for a year and day, and then runs that day. Returns `None` if stdin is closed before a day is picked
+pub fn select_day(config: &Config) -> Option<DayReport> {
     let years = Year::create_years();
 
     let selected_year;
@@ -239,7 +667,7 @@ pub fn select_day() -> Result<(String, String, u128), BoxedError> {
         println!("Year Select:\n");
         Year::display_years(&years);
 
-        let input = get_input("\nPlease Select A Year: ");
+        let input = get_input("\nPlease Select A Year: ")?;
         selected_year = unwrap_or_else!(Year::get_year(&years, &input), error: e, {
             clear().unwrap();
             eprintln!("{e}");
@@ -256,7 +684,7 @@ pub fn select_day() -> Result<(String, String, u128), BoxedError> {
         println!("Days Select For {}:\n", selected_year.year);
         selected_year.display_days();
 
-        let input = get_input("\nPlease Select A Day: ");
+        let input = get_input("\nPlease Select A Day: ")?;
         selected_day = unwrap_or_else!(selected_year.get_day(&input), error: e, {
             clear().unwrap();
             eprintln!("{e}");
@@ -267,14 +695,113 @@ pub fn select_day() -> Result<(String, String, u128), BoxedError> {
     }
 
     clear().unwrap();
+    Some(selected_year.report_day(selected_day, None, config))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::Year;
+    use crate::answers::Verdict;
+    use crate::calendar;
+    use crate::config::Config;
+    use crate::report::Status;
+
+    #[test]
+    fn registered_days() {
+        let years = Year::create_years();
+        let year = Year::get_year(&years, "2022").unwrap();
 
-    let input = unwrap_or_return!(selected_year.get_input(selected_day));
-    let start_time = Instant::now();
-    let (answer_1, answer_2) = selected_day.execute(&input)?;
+        let days: Vec<usize> = year.days.iter().map(|day| day.day).collect();
+        assert_eq!((1..=days.len()).collect::<Vec<usize>>(), days);
 
-    Ok((
-        answer_1,
-        answer_2,
-        Instant::now().duration_since(start_time).as_micros(),
-    ))
+        assert_eq!("Rope Bridge", year.get_day("9").unwrap().name);
+        assert_eq!("Rope Bridge", year.get_day("Day 9").unwrap().name);
+        assert!(year.get_day(&(days.len() + 1).to_string()).is_err());
+    }
+
+    #[test]
+    fn today() {
+        // 12:00:00 in UTC-5 on the given day of December 2022
+        let at_day = |day: usize| 1_669_914_000 + (day as u64 - 1) * 24 * 60 * 60;
+        let day_9 = at_day(9);
+
+        let years = Year::create_years();
+        let year = Year::get_current_year(&years, day_9).unwrap();
+        let latest = year.days.last().unwrap().day;
+
+        assert_eq!(2022, year.year);
+        assert_eq!(9, year.get_today(day_9).unwrap().day);
+
+        // The day after the latest one that's been added, if the event has one
+        if latest < calendar::get_last_day(2022) {
+            let error = year
+                .get_today(at_day(latest + 1))
+                .err()
+                .unwrap()
+                .to_string();
+            assert!(error.contains(&format!("advent_of_code new 2022 {}", latest + 1)));
+        }
+
+        // Outside of the event, the latest day is used
+        assert_eq!(
+            latest,
+            year.get_today(day_9 + 365 * 24 * 60 * 60).unwrap().day
+        );
+        assert_eq!(latest, year.get_today(0).unwrap().day);
+        assert!(Year::get_current_year(&years, day_9 + 365 * 24 * 60 * 60).is_err());
+        assert_eq!(2022, Year::get_current_year(&years, 0).unwrap().year);
+    }
+
+    #[test]
+    fn compare_inputs() {
+        let input_root = env::temp_dir().join(format!("aoc_compare_{}", std::process::id()));
+        let config = Config {
+            input_root: input_root.clone(),
+            read_only: true,
+            ..Default::default()
+        };
+
+        let years = Year::create_years();
+        let year = Year::get_year(&years, "2022").unwrap();
+        let day = year.get_day("1").unwrap();
+
+        assert!(year.compare_inputs(day, &config).is_err());
+
+        let folder = config.get_named_inputs_folder(2022, 1);
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(folder.join("bob.txt"), "1\n\n2\n3\n\n4\n").unwrap();
+        fs::write(folder.join("bob.toml"), "part_1 = 5\npart_2 = 11\n").unwrap();
+        fs::write(folder.join("alice.txt"), "1000\n2000\n").unwrap();
+        fs::write(folder.join("notes.md"), "Not an input").unwrap();
+
+        assert_eq!(
+            vec!["alice".to_string(), "bob".to_string()],
+            year.get_input_names(1, &config).unwrap()
+        );
+
+        let reports = year.compare_inputs(day, &config).unwrap();
+        assert_eq!(2, reports.len());
+
+        assert_eq!(
+            [Some(Verdict::Unknown), Some(Verdict::Unknown)],
+            reports[0].verdicts()
+        );
+        assert!(
+            matches!(&reports[0].status, Status::Completed(answers) if answers.part_1.as_deref() == Ok("3000"))
+        );
+
+        assert_eq!(
+            [
+                Some(Verdict::Correct),
+                Some(Verdict::Wrong {
+                    expected: "11".to_string()
+                })
+            ],
+            reports[1].verdicts()
+        );
+
+        fs::remove_dir_all(input_root).unwrap();
+    }
 }
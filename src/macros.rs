@@ -1,11 +1,67 @@
+/// Builds a boxed `AocError`. The kind can be given before the message (e.g `io: "..."`, `parse: "..."`,
+/// `parse(line, column): "..."`, `parse(line: line): "..."`, `parse(offset: offset): "..."` or
+/// `invalid_state: "..."`), and is `AocError::Other` otherwise
 macro_rules! err_from_str {
+    (not_implemented) => {
+        Box::new(crate::error::AocError::NotImplemented)
+    };
+    (cancelled) => {
+        Box::new(crate::error::AocError::Cancelled)
+    };
+    (io: $($args:tt)*) => {
+        Box::new(crate::error::AocError::Io(format!($($args)*)))
+    };
+    (parse(offset: $offset:expr): $($args:tt)*) => {
+        Box::new(crate::error::AocError::Parse {
+            message: format!($($args)*),
+            location: crate::error::Location {
+                offset: Some($offset),
+                ..Default::default()
+            },
+        })
+    };
+    (parse(line: $line:expr): $($args:tt)*) => {
+        Box::new(crate::error::AocError::Parse {
+            message: format!($($args)*),
+            location: crate::error::Location {
+                line: Some($line),
+                ..Default::default()
+            },
+        })
+    };
+    (parse($line:expr, $column:expr): $($args:tt)*) => {
+        Box::new(crate::error::AocError::Parse {
+            message: format!($($args)*),
+            location: crate::error::Location {
+                line: Some($line),
+                column: Some($column),
+                ..Default::default()
+            },
+        })
+    };
+    (parse: $($args:tt)*) => {
+        Box::new(crate::error::AocError::Parse {
+            message: format!($($args)*),
+            location: Default::default(),
+        })
+    };
+    (invalid_state: $($args:tt)*) => {
+        Box::new(crate::error::AocError::InvalidState(format!($($args)*)))
+    };
     ($($args:tt)*) => {
-        Box::new(simple_error::SimpleError::new(format!($($args)*)))
+        Box::new(crate::error::AocError::Other(format!($($args)*)))
     };
 }
 
 macro_rules! convert_to_err {
+    (not_implemented) => {
+        err_from_str!(not_implemented)
+    };
+    (cancelled) => {
+        err_from_str!(cancelled)
+    };
     ($err:tt) => {{
+        #[allow(dead_code)]
         trait AlreadyError {
             fn convert_to_error(self) -> Self
 			where
@@ -20,8 +76,9 @@ macro_rules! convert_to_err {
         {
         }
 
+        #[allow(dead_code)]
         trait CreateErrFromString: std::fmt::Display {
-            fn convert_to_error(&self) -> Box<simple_error::SimpleError>
+            fn convert_to_error(&self) -> Box<crate::error::AocError>
             {
                 err_from_str!("{}", self)
             }
@@ -39,7 +96,7 @@ macro_rules! convert_to_err {
 
 macro_rules! return_err {
     ($($args:tt)*) => {
-        return Err((convert_to_err!($($args)*)))
+        return Err(convert_to_err!($($args)*))
     };
 }
 
@@ -53,9 +110,9 @@ macro_rules! unwrap_custom_or_else {
 }
 
 macro_rules! unwrap_custom_or_return {
-    ($val:expr, $ok:tt, $err:pat, $($args:expr),*) => {
+    ($val:expr, $ok:tt, $err:pat, $($args:tt)*) => {
 		unwrap_custom_or_else!($val, $ok, $err, {
-			return_err!($($args),*)
+			return_err!($($args)*)
 		})
 	};
 }
@@ -70,11 +127,11 @@ macro_rules! unwrap_or_else {
 }
 
 macro_rules! unwrap_or_return {
-	($val:expr, error: $err:tt, $($args:expr),*) => {
-		unwrap_custom_or_return!($val, Ok, Err($err), $($args),*)
+	($val:expr, error: $err:tt, $($args:tt)*) => {
+		unwrap_custom_or_return!($val, Ok, Err($err), $($args)*)
 	};
-    ($val:expr, $($args:expr),*) => {
-		unwrap_custom_or_return!($val, Ok, Err(_), $($args),*)
+    ($val:expr, $($args:tt)+) => {
+		unwrap_custom_or_return!($val, Ok, Err(_), $($args)*)
 	};
 	($val:expr) => {
 		unwrap_custom_or_return!($val, Ok, Err(e), e)
@@ -88,13 +145,60 @@ macro_rules! _unwrap_option_or_else {
 }
 
 macro_rules! unwrap_option_or_return {
-    ($val:expr, $($args:expr),*) => {
-		unwrap_custom_or_return!($val, Some, None, $($args),*)
+    ($val:expr, $($args:tt)*) => {
+		unwrap_custom_or_return!($val, Some, None, $($args)*)
 	};
 }
 
+/// Registers a `Solution` as a day, so that it's picked up by `Year::create_years`
+/// without needing to be listed anywhere else
+macro_rules! register_day {
+    ($year:expr, $day:expr, $name:expr, $solution:ty) => {
+        inventory::submit! {
+            crate::Day {
+                year: $year,
+                day: $day,
+                name: $name,
+                function: <$solution as crate::Solution>::execute,
+                trim_whitespace: <$solution as crate::Solution>::TRIM_WHITESPACE,
+            }
+        }
+    };
+}
+
+/// Loads one of the example inputs that the `describe` command saves next to the real inputs.
+/// The first example is `example!(2022, 9)`, and the ones after it are `example!(2022, 9, 2)` and so on
+#[cfg(test)]
+macro_rules! example {
+    ($year:literal, $day:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/inputs/",
+            $year,
+            "/day_",
+            $day,
+            ".example.txt"
+        ))
+    };
+    ($year:literal, $day:literal, $index:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/inputs/",
+            $year,
+            "/day_",
+            $day,
+            ".example_",
+            $index,
+            ".txt"
+        ))
+    };
+}
+
 pub(crate) use convert_to_err;
 pub(crate) use err_from_str;
+#[cfg(test)]
+pub(crate) use example;
+pub(crate) use register_day;
 pub(crate) use return_err;
 pub(crate) use unwrap_custom_or_else;
 pub(crate) use unwrap_custom_or_return;
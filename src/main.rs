@@ -1,25 +1,50 @@
 extern crate advent_of_code;
 
+use std::io::{self, IsTerminal};
+use std::process::ExitCode;
+
+use advent_of_code::cli::{Cli, EXIT_FAILURE, EXIT_SUCCESS, EXIT_USAGE};
+use advent_of_code::config::Config;
 use advent_of_code::pause;
+use clap::Parser;
 use clearscreen::clear;
 
-fn main() {
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let config = match Config::load(&cli.config) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+
+    if let Some(command) = cli.command {
+        return command.execute(cli.format, &config);
+    }
+
+    if io::stdin().is_terminal() && io::stdout().is_terminal() {
+        return match advent_of_code::tui::run(&config) {
+            Ok(()) => ExitCode::from(EXIT_SUCCESS),
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::from(EXIT_FAILURE)
+            }
+        };
+    }
+
+    // Without a terminal (e.g when the menus are driven by a script), fall back to the plain numbered menus
+
     loop {
         clear().unwrap();
         println!("Welcome To Bobby Shmurner's Advent Of Code!");
 
-        match advent_of_code::select_day() {
-            Ok((answer_1, answer_2, micros)) => {
-                println!(
-                    "Completed in {:.3} Milliseconds!\n\nPart 1: {answer_1}\nPart 2: {answer_2}",
-                    micros as f32 / 1000.0
-                );
-            }
-            Err(error) => {
-                eprintln!("An error occured: {error}");
-            }
+        let Some(report) = advent_of_code::select_day(&config) else {
+            return ExitCode::from(EXIT_SUCCESS);
         };
 
+        report.print();
         pause("\nPress Enter To Continue...");
     }
 }
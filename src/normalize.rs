@@ -0,0 +1,121 @@
+/// An input after it's been normalized, along with a description of every change that was made
+#[derive(Debug, PartialEq, Eq)]
+pub struct Normalized {
+    pub input: String,
+    pub changes: Vec<String>,
+}
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+fn count(count: usize, noun: &str) -> String {
+    match count {
+        1 => format!("1 {noun}"),
+        _ => format!("{count} {noun}s"),
+    }
+}
+
+/// Cleans up the differences that copying, pasting, or checking out on Windows can make to an input, so that
+/// days only ever see one form of it:
+/// - A leading byte order mark is removed
+/// - CRLF (and lone CR) line endings are converted to LF
+/// - Trailing whitespace is removed from every line, unless `trim_whitespace` is false
+/// - Blank lines at the end are removed, leaving a single final newline
+pub fn normalize(input: &str, trim_whitespace: bool) -> Normalized {
+    let mut changes = Vec::new();
+
+    let input = match input.strip_prefix(BYTE_ORDER_MARK) {
+        Some(input) => {
+            changes.push("removed the byte order mark".to_string());
+            input
+        }
+        None => input,
+    };
+
+    let crlf = input.matches("\r\n").count();
+    let mut input = input.replace("\r\n", "\n");
+    if crlf > 0 {
+        changes.push(format!("converted {}", count(crlf, "CRLF line ending")));
+    }
+
+    let cr = input.matches('\r').count();
+    if cr > 0 {
+        input = input.replace('\r', "\n");
+        changes.push(format!("converted {}", count(cr, "CR line ending")));
+    }
+
+    let mut trimmed = 0;
+    if trim_whitespace {
+        input = input
+            .split('\n')
+            .map(|line| {
+                let trimmed_line = line.trim_end();
+                if trimmed_line.len() != line.len() {
+                    trimmed += 1;
+                }
+
+                trimmed_line
+            })
+            .collect::<Vec<&str>>()
+            .join("\n");
+    }
+
+    if trimmed > 0 {
+        changes.push(format!(
+            "removed trailing whitespace from {}",
+            count(trimmed, "line")
+        ));
+    }
+
+    let content_len = input.trim_end_matches('\n').len();
+    let blank_lines = (input.len() - content_len).saturating_sub(1);
+
+    if blank_lines > 0 {
+        input.truncate(content_len + 1);
+        changes.push(format!(
+            "removed {} from the end",
+            count(blank_lines, "blank line")
+        ));
+    }
+
+    Normalized { input, changes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+
+    #[test]
+    fn normalize_input() {
+        let normalized = normalize("1000\n2000\n\n3000\n", true);
+        assert_eq!("1000\n2000\n\n3000\n", normalized.input);
+        assert!(normalized.changes.is_empty());
+
+        let normalized = normalize("\u{feff}1000\r\n2000 \r\n\r\n3000\t\r\n\r\n\r\n", true);
+        assert_eq!("1000\n2000\n\n3000\n", normalized.input);
+        assert_eq!(
+            vec![
+                "removed the byte order mark",
+                "converted 6 CRLF line endings",
+                "removed trailing whitespace from 2 lines",
+                "removed 2 blank lines from the end",
+            ],
+            normalized.changes
+        );
+
+        let normalized = normalize("R 4\rU 4", true);
+        assert_eq!("R 4\nU 4", normalized.input);
+        assert_eq!(vec!["converted 1 CR line ending"], normalized.changes);
+
+        // Without trimming, the spaces are left alone but the line endings are still converted
+        let normalized = normalize("\u{feff}    [D]    \r\n[N] [C]    \r\n\r\n", false);
+        assert_eq!("    [D]    \n[N] [C]    \n", normalized.input);
+        assert_eq!(
+            vec![
+                "removed the byte order mark",
+                "converted 3 CRLF line endings",
+                "removed 1 blank line from the end",
+            ],
+            normalized.changes
+        );
+    }
+}
@@ -0,0 +1,605 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::answers::{ExpectedAnswers, Verdict};
+use crate::diagnostic;
+use crate::error::AocError;
+use crate::{Answers, PartResult};
+
+pub enum Status {
+    Completed(Answers),
+    Skipped(String),
+    Failed(AocError),
+}
+
+pub struct DayReport {
+    pub year: u32,
+    pub day: usize,
+    pub name: String,
+    pub input_path: String,
+    pub status: Status,
+    pub expected: ExpectedAnswers,
+    /// What was changed by normalizing the input, such as "converted 1000 CRLF line endings"
+    pub input_changes: Vec<String>,
+}
+
+/// A single run, as it's written when using `--format json`
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u32,
+    day: usize,
+    name: &'a str,
+    status: &'a str,
+    part_1: Option<&'a str>,
+    part_2: Option<&'a str>,
+    time_ns: Option<u128>,
+    parse_ns: Option<u128>,
+    part_1_ns: Option<u128>,
+    part_2_ns: Option<u128>,
+    part_1_status: Option<&'a str>,
+    part_2_status: Option<&'a str>,
+    part_1_error: Option<String>,
+    part_2_error: Option<String>,
+    part_1_verdict: Option<&'a str>,
+    part_2_verdict: Option<&'a str>,
+    part_1_expected: Option<&'a str>,
+    part_2_expected: Option<&'a str>,
+    input_path: &'a str,
+    input_changes: &'a [String],
+    /// Why the day was skipped
+    reason: Option<&'a str>,
+    error: Option<String>,
+    error_kind: Option<&'a str>,
+}
+
+/// How a single part went, as it's shown in place of a missing answer. `None` if the part has an answer
+fn get_part_status(result: &PartResult) -> Option<&'static str> {
+    match result {
+        Ok(_) => None,
+        Err(AocError::NotImplemented) => Some("Not Complete"),
+        Err(AocError::TimedOut(_)) => Some("Timed Out"),
+        Err(AocError::Cancelled) => Some("Cancelled"),
+        Err(_) => Some("Failed"),
+    }
+}
+
+/// A part's answer as it fits in a table cell, or how the part went if it has no answer
+pub(crate) fn format_part_cell(result: &PartResult) -> String {
+    match result {
+        Ok(answer) => format_answer(answer),
+        Err(_) => get_part_status(result).unwrap_or_default().to_string(),
+    }
+}
+
+/// A part's answer along with its verdict, or why it doesn't have an answer
+fn format_part(result: &PartResult, verdict: Option<&Verdict>) -> String {
+    match (result, verdict) {
+        (Ok(answer), Some(verdict)) => format!("{answer} {verdict}"),
+        (Ok(answer), None) => answer.clone(),
+        (Err(AocError::NotImplemented), _) => "Not Complete".to_string(),
+        (Err(error), _) => format!("An error occured: {error}"),
+    }
+}
+
+impl DayReport {
+    /// Compares each part's answer against the expected answers. A part is `None` if it has no answer
+    pub fn verdicts(&self) -> [Option<Verdict>; 2] {
+        match &self.status {
+            Status::Completed(answers) => self.check_answers(answers),
+            _ => [None, None],
+        }
+    }
+
+    fn check_answers(&self, answers: &Answers) -> [Option<Verdict>; 2] {
+        [
+            (&self.expected.part_1, &answers.part_1),
+            (&self.expected.part_2, &answers.part_2),
+        ]
+        .map(|(expected, result)| {
+            let answer = result.as_ref().ok()?;
+            Some(Verdict::check(expected.as_deref(), answer))
+        })
+    }
+
+    pub fn has_wrong_answer(&self) -> bool {
+        self.verdicts()
+            .iter()
+            .flatten()
+            .any(|verdict| matches!(verdict, Verdict::Wrong { .. }))
+    }
+
+    /// Whether the day, or either of its parts, failed. Parts that aren't complete yet haven't failed
+    pub fn has_failed(&self) -> bool {
+        match &self.status {
+            Status::Completed(answers) => answers.get_error().is_some(),
+            Status::Skipped(_) => false,
+            Status::Failed(_) => true,
+        }
+    }
+
+    /// The result as it's printed by `print`, without the note about normalizing the input
+    pub fn to_text(&self) -> String {
+        match &self.status {
+            Status::Completed(answers) => {
+                let [verdict_1, verdict_2] = self.check_answers(answers);
+
+                format!(
+                    "Completed in {:.3} Milliseconds!\n  Parse:  {}\n  Part 1: {}\n  Part 2: {}\n\nPart 1: {}\nPart 2: {}",
+                    answers.timings.total() as f64 / 1_000_000.0,
+                    format_millis(answers.timings.parse),
+                    format_millis(answers.timings.part_1),
+                    format_millis(answers.timings.part_2),
+                    format_part(&answers.part_1, verdict_1.as_ref()),
+                    format_part(&answers.part_2, verdict_2.as_ref()),
+                )
+            }
+            Status::Skipped(reason) => format!("Skipped: {reason}"),
+            Status::Failed(error) => diagnostic::render(error, &self.input_path)
+                .unwrap_or_else(|| format!("An error occured: {error}")),
+        }
+    }
+
+    pub fn print(&self) {
+        if !self.input_changes.is_empty() {
+            eprintln!("Normalized the input: {}\n", self.input_changes.join(", "));
+        }
+
+        match &self.status {
+            Status::Completed(_) => println!("{}", self.to_text()),
+            _ => eprintln!("{}", self.to_text()),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let reason = match &self.status {
+            Status::Skipped(reason) => Some(reason.as_str()),
+            _ => None,
+        };
+
+        let (status, answers, error, error_kind) = match &self.status {
+            // A day where either part failed has failed, even though the other part might have an answer
+            Status::Completed(answers) => match answers.get_error() {
+                Some(error) => (
+                    "failed",
+                    Some(answers),
+                    Some(error.to_string()),
+                    Some(error.kind()),
+                ),
+                None => ("completed", Some(answers), None, None),
+            },
+            Status::Skipped(_) => ("skipped", None, None, None),
+            Status::Failed(error) => ("failed", None, Some(error.to_string()), Some(error.kind())),
+        };
+
+        let [verdict_1, verdict_2] = self.verdicts();
+        let parts = answers.map(|answers| [&answers.part_1, &answers.part_2]);
+        let get_status = |part: usize| {
+            let result = parts?[part];
+            Some(
+                get_part_status(result).map_or("completed", |_| match result {
+                    Err(AocError::NotImplemented) => "skipped",
+                    _ => "failed",
+                }),
+            )
+        };
+        let get_error = |part: usize| parts?[part].as_ref().err().map(ToString::to_string);
+
+        let record = Record {
+            year: self.year,
+            day: self.day,
+            name: &self.name,
+            status,
+            part_1: answers.and_then(|answers| answers.part_1.as_deref().ok()),
+            part_2: answers.and_then(|answers| answers.part_2.as_deref().ok()),
+            time_ns: answers.map(|answers| answers.timings.total()),
+            parse_ns: answers.map(|answers| answers.timings.parse),
+            part_1_ns: answers.map(|answers| answers.timings.part_1),
+            part_2_ns: answers.map(|answers| answers.timings.part_2),
+            part_1_status: get_status(0),
+            part_2_status: get_status(1),
+            part_1_error: get_error(0),
+            part_2_error: get_error(1),
+            part_1_verdict: verdict_1.as_ref().map(Verdict::name),
+            part_2_verdict: verdict_2.as_ref().map(Verdict::name),
+            part_1_expected: self.expected.part_1.as_deref(),
+            part_2_expected: self.expected.part_2.as_deref(),
+            input_path: &self.input_path,
+            input_changes: &self.input_changes,
+            reason,
+            error,
+            error_kind,
+        };
+
+        serde_json::to_string(&record).unwrap()
+    }
+
+    pub(crate) fn get_input_label(&self) -> String {
+        Path::new(&self.input_path)
+            .file_stem()
+            .map_or(self.input_path.clone(), |stem| {
+                stem.to_string_lossy().to_string()
+            })
+    }
+
+    fn get_answer(&self, part: usize) -> Option<&str> {
+        match &self.status {
+            Status::Completed(answers) if part == 0 => answers.part_1.as_deref().ok(),
+            Status::Completed(answers) => answers.part_2.as_deref().ok(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_row(&self) -> [String; 10] {
+        let mut row = [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.name.clone(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ];
+
+        match &self.status {
+            Status::Completed(answers) => {
+                let verdicts = self.check_answers(answers);
+                let mut part_statuses = Vec::new();
+
+                for (i, (result, verdict)) in [&answers.part_1, &answers.part_2]
+                    .into_iter()
+                    .zip(verdicts)
+                    .enumerate()
+                {
+                    row[3 + i] = match verdict {
+                        Some(verdict) => {
+                            format!("{} {}", format_part_cell(result), verdict.symbol())
+                        }
+                        None => format_part_cell(result),
+                    };
+
+                    if let Some(status) = get_part_status(result) {
+                        part_statuses.push(format!("Part {} {status}", i + 1));
+                    }
+                }
+
+                row[5] = format_millis(answers.timings.parse);
+                row[6] = format_millis(answers.timings.part_1);
+                row[7] = format_millis(answers.timings.part_2);
+                row[8] = format_millis(answers.timings.total());
+                row[9] = match part_statuses.is_empty() {
+                    true => "Completed".to_string(),
+                    false => part_statuses.join(", "),
+                };
+            }
+            Status::Skipped(reason) => row[9] = format!("Skipped ({reason})"),
+            Status::Failed(AocError::TimedOut(_)) => row[9] = "Timed Out".to_string(),
+            Status::Failed(AocError::Cancelled) => row[9] = "Cancelled".to_string(),
+            Status::Failed(_) => row[9] = "Failed".to_string(),
+        }
+
+        row
+    }
+}
+
+pub(crate) fn format_millis(nanos: u128) -> String {
+    format!("{:.3} ms", nanos as f64 / 1_000_000.0)
+}
+
+/// Multi-line answers (such as the ones drawn on a CRT) don't fit in a table cell
+pub(crate) fn format_answer(answer: &str) -> String {
+    let answer = answer.trim();
+
+    match answer.lines().count() {
+        0 | 1 => answer.to_string(),
+        lines => format!("<{lines} lines>"),
+    }
+}
+
+/// Prints the rows as a table, with each column padded to fit its widest cell
+pub(crate) fn print_rows<const N: usize>(headers: &[&str; N], rows: &[[String; N]]) {
+    let mut widths = headers.map(|header| header.chars().count());
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<String>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!("{}", format_row(&headers.map(str::to_string)));
+    println!("{}", widths.map(|width| "-".repeat(width)).join("-+-"));
+
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
+
+pub fn print_table(reports: &[DayReport]) {
+    const HEADERS: [&str; 10] = [
+        "Year",
+        "Day",
+        "Name",
+        "Part 1",
+        "Part 2",
+        "Parse Time",
+        "Part 1 Time",
+        "Part 2 Time",
+        "Total Time",
+        "Status",
+    ];
+
+    let rows: Vec<[String; 10]> = reports.iter().map(DayReport::get_row).collect();
+    print_rows(&HEADERS, &rows);
+
+    print_summary(reports, |report| {
+        format!("Year {}, Day {}", report.year, report.day)
+    });
+}
+
+/// Prints the answers for the same day from several inputs. Each input is labelled with its file name
+/// (e.g. "alice" for "day_9/alice.txt")
+pub fn print_comparison(reports: &[DayReport]) {
+    const HEADERS: [&str; 5] = ["Input", "Part 1", "Part 2", "Total Time", "Status"];
+
+    let rows: Vec<[String; 5]> = reports
+        .iter()
+        .map(|report| {
+            let [_, _, _, part_1, part_2, _, _, _, total, status] = report.get_row();
+            [report.get_input_label(), part_1, part_2, total, status]
+        })
+        .collect();
+
+    if let Some(report) = reports.first() {
+        println!(
+            "Year {}, Day {}: {}\n",
+            report.year, report.day, report.name
+        );
+    }
+
+    print_rows(&HEADERS, &rows);
+    print_summary(reports, DayReport::get_input_label);
+}
+
+/// Prints how many days completed, followed by every failure and wrong answer
+pub(crate) fn print_summary(reports: &[DayReport], label: impl Fn(&DayReport) -> String) {
+    let mut completed = 0;
+    let mut skipped = 0;
+    let mut failed_days = 0;
+    let mut failed = Vec::new();
+
+    for report in reports {
+        match &report.status {
+            Status::Completed(answers) if answers.get_error().is_none() => completed += 1,
+            Status::Completed(answers) => {
+                failed_days += 1;
+
+                for (i, result) in [&answers.part_1, &answers.part_2].into_iter().enumerate() {
+                    if let Err(error) = result {
+                        if *error != AocError::NotImplemented {
+                            failed.push((report, Some(i + 1), error));
+                        }
+                    }
+                }
+            }
+            Status::Skipped(_) => skipped += 1,
+            Status::Failed(error) => {
+                failed_days += 1;
+                failed.push((report, None, error));
+            }
+        }
+    }
+
+    println!("\n{completed} Completed, {skipped} Skipped, {failed_days} Failed");
+
+    for report in reports {
+        if !report.input_changes.is_empty() {
+            println!(
+                "\n{} Input Was Normalized: {}",
+                label(report),
+                report.input_changes.join(", ")
+            );
+        }
+    }
+
+    for (report, part, error) in failed {
+        match part {
+            Some(part) => println!("\n{}, Part {part} Failed: {error}", label(report)),
+            None => println!("\n{} Failed: {error}", label(report)),
+        }
+    }
+
+    for report in reports {
+        for (i, verdict) in report.verdicts().iter().enumerate() {
+            if let Some(Verdict::Wrong { expected }) = verdict {
+                println!(
+                    "\n{}, Part {} Is Wrong!\nExpected: {expected}\nActual:   {}",
+                    label(report),
+                    i + 1,
+                    report.get_answer(i).unwrap_or_default().trim()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DayReport, Status};
+    use crate::answers::{ExpectedAnswers, Verdict};
+    use crate::error::{AocError, Location};
+    use crate::{Answers, Timings};
+
+    #[test]
+    fn json_record() {
+        let report = DayReport {
+            year: 2022,
+            day: 9,
+            name: "Rope Bridge".to_string(),
+            input_path: "./inputs/2022/day_9.txt".to_string(),
+            status: Status::Completed(Answers {
+                part_1: Ok("13".to_string()),
+                part_2: Ok("1".to_string()),
+                timings: Timings {
+                    parse: 500,
+                    part_1: 400,
+                    part_2: 600,
+                },
+            }),
+            expected: ExpectedAnswers {
+                part_1: Some("13".to_string()),
+                part_2: None,
+            },
+            input_changes: Vec::new(),
+        };
+
+        assert_eq!(
+            r#"{"year":2022,"day":9,"name":"Rope Bridge","status":"completed","part_1":"13","part_2":"1","time_ns":1500,"parse_ns":500,"part_1_ns":400,"part_2_ns":600,"part_1_status":"completed","part_2_status":"completed","part_1_error":null,"part_2_error":null,"part_1_verdict":"correct","part_2_verdict":"unknown","part_1_expected":"13","part_2_expected":null,"input_path":"./inputs/2022/day_9.txt","input_changes":[],"reason":null,"error":null,"error_kind":null}"#,
+            report.to_json()
+        );
+
+        let report = DayReport {
+            status: Status::Failed(AocError::Parse {
+                message: "Invalid Direction \"X\"".to_string(),
+                location: Location {
+                    line: Some(4),
+                    ..Location::default()
+                },
+            }),
+            ..report
+        };
+
+        assert_eq!(
+            r#"{"year":2022,"day":9,"name":"Rope Bridge","status":"failed","part_1":null,"part_2":null,"time_ns":null,"parse_ns":null,"part_1_ns":null,"part_2_ns":null,"part_1_status":null,"part_2_status":null,"part_1_error":null,"part_2_error":null,"part_1_verdict":null,"part_2_verdict":null,"part_1_expected":"13","part_2_expected":null,"input_path":"./inputs/2022/day_9.txt","input_changes":[],"reason":null,"error":"Invalid Direction \"X\" (Line 4)","error_kind":"parse"}"#,
+            report.to_json()
+        );
+
+        let report = DayReport {
+            status: Status::Skipped("No Input".to_string()),
+            ..report
+        };
+
+        assert_eq!(
+            r#"{"year":2022,"day":9,"name":"Rope Bridge","status":"skipped","part_1":null,"part_2":null,"time_ns":null,"parse_ns":null,"part_1_ns":null,"part_2_ns":null,"part_1_status":null,"part_2_status":null,"part_1_error":null,"part_2_error":null,"part_1_verdict":null,"part_2_verdict":null,"part_1_expected":"13","part_2_expected":null,"input_path":"./inputs/2022/day_9.txt","input_changes":[],"reason":"No Input","error":null,"error_kind":null}"#,
+            report.to_json()
+        );
+
+        let report = DayReport {
+            status: Status::Completed(Answers {
+                part_1: Ok("13".to_string()),
+                part_2: Err(AocError::NotImplemented),
+                timings: Timings::default(),
+            }),
+            ..report
+        };
+
+        assert_eq!(
+            r#"{"year":2022,"day":9,"name":"Rope Bridge","status":"completed","part_1":"13","part_2":null,"time_ns":0,"parse_ns":0,"part_1_ns":0,"part_2_ns":0,"part_1_status":"completed","part_2_status":"skipped","part_1_error":null,"part_2_error":"Code For This Day Is Not Complete!","part_1_verdict":"correct","part_2_verdict":null,"part_1_expected":"13","part_2_expected":null,"input_path":"./inputs/2022/day_9.txt","input_changes":[],"reason":null,"error":null,"error_kind":null}"#,
+            report.to_json()
+        );
+    }
+
+    #[test]
+    fn json_record_with_failed_parts() {
+        let panic = |message: &str| AocError::Panic {
+            message: message.to_string(),
+            location: Some("src/year_2022/day_12.rs:40:10".to_string()),
+        };
+
+        let report = DayReport {
+            year: 2022,
+            day: 12,
+            name: "Hill Climbing Algorithm".to_string(),
+            input_path: "-".to_string(),
+            status: Status::Completed(Answers {
+                part_1: Err(panic("No Path Found")),
+                part_2: Err(panic("No Path Found")),
+                timings: Timings::default(),
+            }),
+            expected: ExpectedAnswers::default(),
+            input_changes: Vec::new(),
+        };
+
+        assert_eq!(
+            r#"{"year":2022,"day":12,"name":"Hill Climbing Algorithm","status":"failed","part_1":null,"part_2":null,"time_ns":0,"parse_ns":0,"part_1_ns":0,"part_2_ns":0,"part_1_status":"failed","part_2_status":"failed","part_1_error":"Panicked at src/year_2022/day_12.rs:40:10: No Path Found","part_2_error":"Panicked at src/year_2022/day_12.rs:40:10: No Path Found","part_1_verdict":null,"part_2_verdict":null,"part_1_expected":null,"part_2_expected":null,"input_path":"-","input_changes":[],"reason":null,"error":"Panicked at src/year_2022/day_12.rs:40:10: No Path Found","error_kind":"panic"}"#,
+            report.to_json()
+        );
+    }
+
+    #[test]
+    fn verdicts() {
+        let report = DayReport {
+            year: 2022,
+            day: 1,
+            name: "Calorie Counting".to_string(),
+            input_path: "./inputs/2022/day_1.txt".to_string(),
+            status: Status::Completed(Answers {
+                part_1: Ok("24000".to_string()),
+                part_2: Ok("45001".to_string()),
+                timings: Timings::default(),
+            }),
+            expected: ExpectedAnswers {
+                part_1: Some("24000".to_string()),
+                part_2: Some("45000".to_string()),
+            },
+            input_changes: Vec::new(),
+        };
+
+        assert_eq!(
+            [
+                Some(Verdict::Correct),
+                Some(Verdict::Wrong {
+                    expected: "45000".to_string()
+                })
+            ],
+            report.verdicts()
+        );
+        assert!(report.has_wrong_answer());
+        assert!(!report.has_failed());
+
+        // Part 1 is still checked when Part 2 fails
+        let report = DayReport {
+            status: Status::Completed(Answers {
+                part_1: Ok("24000".to_string()),
+                part_2: Err(AocError::InvalidState("No Elves Found".to_string())),
+                timings: Timings::default(),
+            }),
+            ..report
+        };
+
+        assert_eq!([Some(Verdict::Correct), None], report.verdicts());
+        assert!(!report.has_wrong_answer());
+        assert!(report.has_failed());
+
+        let row = report.get_row();
+        assert_eq!(["24000 ✔", "Failed"], [&row[3], &row[4]]);
+        assert_eq!("Part 2 Failed", row[9]);
+
+        let report = DayReport {
+            status: Status::Skipped("No Input".to_string()),
+            ..report
+        };
+
+        assert_eq!([None, None], report.verdicts());
+        assert!(!report.has_wrong_answer());
+    }
+
+    #[test]
+    fn format_answer() {
+        assert_eq!("24000", super::format_answer("24000"));
+        assert_eq!("<2 lines>", super::format_answer("\n\n##..\n..##"));
+    }
+}
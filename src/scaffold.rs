@@ -0,0 +1,339 @@
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::config::Config;
+use crate::macros::*;
+use crate::BoxedError;
+
+lazy_static! {
+    static ref MODULE: Regex = Regex::new(r"^pub mod (\w+);$").unwrap();
+}
+
+/// Fills in the placeholders in `day_template.rs` for a new day
+pub fn render_template(
+    template: &str,
+    year: u32,
+    day_num: usize,
+    title: &str,
+) -> Result<String, BoxedError> {
+    let registration = "register_day!(0, 0, \"Title\", DayN);";
+
+    if !template.contains(registration) {
+        return_err!(
+            "The template doesn't register the day with \"{}\"",
+            registration
+        );
+    }
+
+    Ok(template
+        .replace(
+            registration,
+            &format!("register_day!({year}, {day_num}, {title:?}, Day{day_num});"),
+        )
+        .replace("example!(0, 0)", &format!("example!({year}, {day_num})"))
+        .replace("DayN", &format!("Day{day_num}")))
+}
+
+/// Adds `pub mod {module};` to a list of module declarations, keeping them in the same order as rustfmt.
+/// Returns `None` if the module is already declared
+pub fn add_module(source: &str, module: &str) -> Option<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let declaration = format!("pub mod {module};");
+
+    let modules: Vec<(usize, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, MODULE.captures(line)?.get(1)?.as_str())))
+        .collect();
+
+    if modules.iter().any(|(_, name)| *name == module) {
+        return None;
+    }
+
+    let index = match modules.iter().find(|(_, name)| *name > module) {
+        Some((i, _)) => *i,
+        None => modules.last().map_or(0, |(i, _)| i + 1),
+    };
+
+    lines.insert(index, &declaration);
+    Some(lines.join("\n") + "\n")
+}
+
+fn create_file(path: &Path, contents: &str) -> Result<(), BoxedError> {
+    if let Some(folder) = path.parent() {
+        unwrap_or_return!(
+            fs::create_dir_all(folder),
+            error: e,
+            io: "Failed to create folder \"{}\"\nReason: {}",
+            folder.display(),
+            e
+        );
+    }
+
+    let mut file = unwrap_or_return!(
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path),
+        error: e,
+        io: "Failed to create file \"{}\"\nReason: {}",
+        path.display(),
+        e
+    );
+
+    unwrap_or_return!(
+        file.write_all(contents.as_bytes()),
+        error: e,
+        io: "Failed to write \"{}\"\nReason: {}",
+        path.display(),
+        e
+    );
+
+    Ok(())
+}
+
+fn read_file(path: &Path) -> Result<String, BoxedError> {
+    Ok(unwrap_or_return!(
+        fs::read_to_string(path),
+        error: e,
+        io: "Failed to open \"{}\"\nReason: {}",
+        path.display(),
+        e
+    ))
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), BoxedError> {
+    unwrap_or_return!(
+        fs::write(path, contents),
+        error: e,
+        io: "Failed to write \"{}\"\nReason: {}",
+        path.display(),
+        e
+    );
+
+    Ok(())
+}
+
+/// Finds the root of this crate, which is the nearest folder (starting from the current one) with a
+/// `Cargo.toml` in it
+pub(crate) fn find_crate_root() -> Result<PathBuf, BoxedError> {
+    let current = unwrap_or_return!(
+        env::current_dir(),
+        error: e,
+        io: "Failed to get the current folder\nReason: {}",
+        e
+    );
+
+    let root = current
+        .ancestors()
+        .find(|folder| folder.join("Cargo.toml").is_file());
+
+    Ok(unwrap_option_or_return!(
+        root,
+        "Couldn't find the crate (a folder with a Cargo.toml) in \"{}\" or above it. Run this from inside the crate, or give it with --root",
+        current.display()
+    )
+    .to_path_buf())
+}
+
+/// Where the tests load an example from with `example!`, which is always in the crate's own inputs folder,
+/// wherever the real inputs are kept
+pub(crate) fn get_example_path(root: &Path, year: u32, day_num: usize, index: usize) -> PathBuf {
+    let name = match index {
+        0 | 1 => format!("day_{day_num}.example.txt"),
+        _ => format!("day_{day_num}.example_{index}.txt"),
+    };
+
+    root.join("inputs").join(year.to_string()).join(name)
+}
+
+/// Writes each file in turn. If one of them fails, the ones before it are put back the way they were, so
+/// that a half-created day doesn't break the build. `None` means the file didn't exist before
+fn write_all(files: &[(PathBuf, Option<String>, String)]) -> Result<(), BoxedError> {
+    for (i, (path, original, contents)) in files.iter().enumerate() {
+        let result = match original {
+            Some(_) => write_file(path, contents),
+            None => create_file(path, contents),
+        };
+
+        if let Err(e) = result {
+            for (path, original, _) in files[..i].iter().rev() {
+                match original {
+                    Some(original) => fs::write(path, original).ok(),
+                    None => fs::remove_file(path).ok(),
+                };
+            }
+
+            return Err(e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates a day's module from `src/day_template.rs` in the crate at `root`, declares it in the year's
+/// `mod.rs` (creating the year if needed), and creates its empty input and example files (unless the config
+/// is read-only). Nothing that already exists is overwritten, and every file is read before any are written.
+/// Returns the paths that were written
+pub(crate) fn create_day(
+    root: &Path,
+    config: &Config,
+    year: u32,
+    day_num: usize,
+    title: &str,
+) -> Result<Vec<String>, BoxedError> {
+    let year_folder = root.join("src").join(format!("year_{year}"));
+    let module_path = year_folder.join(format!("day_{day_num}.rs"));
+    let year_mod_path = year_folder.join("mod.rs");
+
+    if module_path.exists() {
+        return_err!(
+            "Year {}, Day {} already exists (Path: \"{}\")",
+            year,
+            day_num,
+            module_path.display()
+        );
+    }
+
+    let template = read_file(&root.join("src").join("day_template.rs"))?;
+    let module = render_template(&template, year, day_num, title)?;
+
+    let original_year_mod = if year_mod_path.exists() {
+        Some(read_file(&year_mod_path)?)
+    } else {
+        None
+    };
+
+    let year_mod = unwrap_option_or_return!(
+        add_module(
+            original_year_mod.as_deref().unwrap_or_default(),
+            &format!("day_{day_num}")
+        ),
+        "The module \"day_{}\" is already declared in \"{}\"",
+        day_num,
+        year_mod_path.display()
+    );
+
+    let mut files = vec![(module_path, None, module)];
+
+    // A new year also has to be declared in lib.rs
+    if original_year_mod.is_none() {
+        let lib_path = root.join("src").join("lib.rs");
+        let lib = read_file(&lib_path)?;
+
+        if let Some(new_lib) = add_module(&lib, &format!("year_{year}")) {
+            files.push((lib_path, Some(lib), new_lib));
+        }
+    }
+
+    files.push((year_mod_path, original_year_mod, year_mod));
+
+    if !config.read_only {
+        let example_path = get_example_path(root, year, day_num, 1);
+
+        if !example_path.exists() {
+            files.push((example_path, None, String::new()));
+        }
+
+        let input_path = config.get_input_path(year, day_num);
+
+        if !input_path.exists() {
+            files.push((input_path, None, String::new()));
+        }
+    }
+
+    write_all(&files)?;
+
+    Ok(files
+        .iter()
+        .map(|(path, _, _)| path.display().to_string())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    #[test]
+    fn render_template() {
+        let template = r#"pub struct DayN;
+
+register_day!(0, 0, "Title", DayN);
+
+    fn part1_example() {
+        let result = DayN::execute(example!(0, 0)).unwrap().part_1.unwrap();
+    }
+"#;
+
+        assert_eq!(
+            r#"pub struct Day15;
+
+register_day!(2022, 15, "Beacon \"Exclusion\" Zone", Day15);
+
+    fn part1_example() {
+        let result = Day15::execute(example!(2022, 15)).unwrap().part_1.unwrap();
+    }
+"#,
+            super::render_template(template, 2022, 15, "Beacon \"Exclusion\" Zone").unwrap()
+        );
+
+        assert!(super::render_template("pub struct DayN;", 2022, 15, "Title").is_err());
+    }
+
+    #[test]
+    fn add_module() {
+        let year_mod =
+            "pub mod day_1;\npub mod day_10;\npub mod day_14;\npub mod day_2;\npub mod day_9;\n";
+
+        assert_eq!(
+            Some("pub mod day_1;\npub mod day_10;\npub mod day_14;\npub mod day_15;\npub mod day_2;\npub mod day_9;\n".to_string()),
+            super::add_module(year_mod, "day_15")
+        );
+        assert_eq!(None, super::add_module(year_mod, "day_2"));
+        assert_eq!(
+            Some("pub mod day_1;\n".to_string()),
+            super::add_module("", "day_1")
+        );
+
+        let lib = "use std::fs;\n\npub mod answers;\nmod client;\npub mod year_2022;\n\npub type X = u32;\n";
+
+        assert_eq!(
+            Some("use std::fs;\n\npub mod answers;\nmod client;\npub mod year_2022;\npub mod year_2023;\n\npub type X = u32;\n".to_string()),
+            super::add_module(lib, "year_2023")
+        );
+    }
+
+    #[test]
+    fn write_all_or_nothing() {
+        let folder = env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(&folder).unwrap();
+
+        let module_path = folder.join("day_15.rs");
+        let mod_path = folder.join("mod.rs");
+        fs::write(&mod_path, "pub mod day_1;\n").unwrap();
+
+        // The folder can't be created, since there's a file in the way
+        let blocked_path = folder.join("mod.rs").join("day_15.example.txt");
+
+        let files = [
+            (module_path.clone(), None, "pub struct Day15;\n".to_string()),
+            (
+                mod_path.clone(),
+                Some("pub mod day_1;\n".to_string()),
+                "pub mod day_1;\npub mod day_15;\n".to_string(),
+            ),
+            (blocked_path, None, String::new()),
+        ];
+
+        assert!(super::write_all(&files).is_err());
+        assert!(!module_path.exists());
+        assert_eq!("pub mod day_1;\n", fs::read_to_string(&mod_path).unwrap());
+
+        fs::remove_dir_all(folder).unwrap();
+    }
+}
@@ -0,0 +1,425 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::macros::*;
+use crate::BoxedError;
+
+lazy_static! {
+    static ref ARTICLE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref TOO_RECENT_WAIT: Regex =
+        Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    static ref WRONG_WAIT: Regex =
+        Regex::new(r"(?i)wait (one|\d+) minutes? before trying again").unwrap();
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, so this one wasn't checked
+    TooRecent,
+    /// The part was already solved, or isn't unlocked yet
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "That's the right answer!",
+            Outcome::Wrong => "That's not the right answer",
+            Outcome::TooHigh => "That's not the right answer, it's too high",
+            Outcome::TooLow => "That's not the right answer, it's too low",
+            Outcome::TooRecent => "An answer was submitted too recently",
+            Outcome::WrongLevel => "This part was already solved, or isn't unlocked yet",
+            Outcome::Unknown => "The response wasn't understood",
+        }
+    }
+
+    /// Whether the answer was checked and found to be wrong
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+/// What the server said about a submitted answer
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// How long until another answer can be submitted
+    pub wait_seconds: Option<u64>,
+    /// The text of the response, without any HTML
+    pub message: String,
+}
+
+impl Response {
+    pub fn parse(html: &str) -> Self {
+        let article = match ARTICLE.captures(html) {
+            Some(caps) => caps[1].to_string(),
+            None => html.to_string(),
+        };
+
+        let message = TAG
+            .replace_all(&article, "")
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        let outcome = if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Outcome::TooHigh
+            } else if message.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Outcome::TooRecent
+        } else if message.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown
+        };
+
+        let wait_seconds = if let Some(caps) = TOO_RECENT_WAIT.captures(&message) {
+            let minutes: u64 = caps.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+            let seconds: u64 = caps[2].parse().unwrap_or(0);
+
+            Some(minutes * 60 + seconds)
+        } else {
+            WRONG_WAIT.captures(&message).map(|caps| {
+                let minutes = match &caps[1] {
+                    "one" => 1,
+                    minutes => minutes.parse().unwrap_or(1),
+                };
+
+                minutes * 60
+            })
+        };
+
+        Self {
+            outcome,
+            wait_seconds,
+            message,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Submission {
+    pub day: usize,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub wait_seconds: Option<u64>,
+    /// When the answer was submitted, in seconds since the Unix epoch
+    pub submitted_at: u64,
+}
+
+/// Every answer submitted for a year, as stored in `submissions.toml`:
+///
+/// ```toml
+/// [[submission]]
+/// day = 9
+/// part = 1
+/// answer = "6000"
+/// outcome = "too_high"
+/// wait_seconds = 60
+/// submitted_at = 1670572800
+/// ```
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct SubmissionLog {
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Loads the log at `path`. A missing file is treated as there being no submissions
+    pub fn load(path: &Path) -> Result<Self, BoxedError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(unwrap_or_return!(
+                toml::from_str(&contents),
+                error: e,
+                "Invalid submission log (Path: \"{}\")\nReason: {}",
+                path.display(),
+                e
+            )),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => return_err!(
+                io: "Failed to open submission log (Path: \"{}\")\nReason: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BoxedError> {
+        let contents = unwrap_or_return!(
+            toml::to_string(self),
+            error: e,
+            "Failed to serialize the submission log\nReason: {}",
+            e
+        );
+
+        if let Some(folder) = path.parent() {
+            unwrap_or_return!(
+                fs::create_dir_all(folder),
+                error: e,
+                io: "Failed to create folder \"{}\"\nReason: {}",
+                folder.display(),
+                e
+            );
+        }
+
+        unwrap_or_return!(
+            fs::write(path, contents),
+            error: e,
+            io: "Failed to write submission log (Path: \"{}\")\nReason: {}",
+            path.display(),
+            e
+        );
+
+        Ok(())
+    }
+
+    /// Returns an error explaining why the answer shouldn't be submitted, if there's a reason not to.
+    /// Each refusal is a submission that would have been wrong, or would have been ignored
+    pub fn check(&self, day: usize, part: u8, answer: &str, now: u64) -> Result<(), BoxedError> {
+        if let Some(wait_until) = self
+            .submissions
+            .iter()
+            .filter_map(|submission| Some(submission.submitted_at + submission.wait_seconds?))
+            .max()
+        {
+            if wait_until > now {
+                return_err!(
+                    "An answer was submitted too recently. Please wait another {}s",
+                    wait_until - now
+                );
+            }
+        }
+
+        let submissions: Vec<&Submission> = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.day == day && submission.part == part)
+            .collect();
+
+        if let Some(correct) = submissions
+            .iter()
+            .find(|submission| submission.outcome == Outcome::Correct)
+        {
+            return_err!(
+                "Part {} of Day {} was already solved with the answer \"{}\"",
+                part,
+                day,
+                correct.answer
+            );
+        }
+
+        if submissions
+            .iter()
+            .any(|submission| submission.outcome.is_wrong() && submission.answer == answer)
+        {
+            return_err!("\"{}\" was already submitted, and is wrong", answer);
+        }
+
+        if let Ok(answer) = answer.parse::<i64>() {
+            let bound = |outcome: Outcome| {
+                submissions
+                    .iter()
+                    .filter(move |submission| submission.outcome == outcome)
+                    .filter_map(|submission| submission.answer.parse::<i64>().ok())
+            };
+
+            if let Some(too_high) = bound(Outcome::TooHigh).min() {
+                if answer >= too_high {
+                    return_err!(
+                        "{} is wrong, since {} was already too high",
+                        answer,
+                        too_high
+                    );
+                }
+            }
+
+            if let Some(too_low) = bound(Outcome::TooLow).max() {
+                if answer <= too_low {
+                    return_err!("{} is wrong, since {} was already too low", answer, too_low);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(
+        &mut self,
+        day: usize,
+        part: u8,
+        answer: &str,
+        response: &Response,
+        now: u64,
+    ) -> &Submission {
+        self.submissions.push(Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome: response.outcome,
+            wait_seconds: response.wait_seconds,
+            submitted_at: now,
+        });
+
+        self.submissions.last().unwrap()
+    }
+}
+
+/// A single submission, as it's written when using `--format json`
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u32,
+    #[serde(flatten)]
+    submission: &'a Submission,
+    message: &'a str,
+}
+
+impl Submission {
+    pub fn print(&self, message: &str) {
+        println!(
+            "Day {}, Part {}: {}\n{}\n\n{message}",
+            self.day,
+            self.part,
+            self.answer,
+            self.outcome.describe()
+        );
+    }
+
+    pub fn to_json(&self, year: u32, message: &str) -> String {
+        let record = Record {
+            year,
+            submission: self,
+            message,
+        };
+
+        serde_json::to_string(&record).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Outcome, Response, SubmissionLog};
+    use crate::client::stub::serve_once;
+    use crate::client::Client;
+    use crate::config::Config;
+
+    fn response(message: &str) -> Response {
+        Response::parse(&format!(
+            "<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>"
+        ))
+    }
+
+    #[test]
+    fn parse_responses() {
+        let correct = response("That's the right answer!  You are <em>one gold star</em> closer to saving your vacation.");
+        assert_eq!(Outcome::Correct, correct.outcome);
+        assert_eq!(None, correct.wait_seconds);
+        assert!(correct
+            .message
+            .starts_with("That's the right answer! You are one gold star"));
+
+        let too_high = response("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2022/day/9\">[Return to Day 9]</a>");
+        assert_eq!(Outcome::TooHigh, too_high.outcome);
+        assert_eq!(Some(60), too_high.wait_seconds);
+
+        let too_low = response("That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.");
+        assert_eq!(Outcome::TooLow, too_low.outcome);
+        assert_eq!(Some(300), too_low.wait_seconds);
+
+        assert_eq!(
+            Outcome::Wrong,
+            response("That's not the right answer.  If you're stuck, make sure you're using the full input data.").outcome
+        );
+
+        let too_recent = response("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 32s left to wait. <a href=\"/2022/day/9\">[Return to Day 9]</a>");
+        assert_eq!(Outcome::TooRecent, too_recent.outcome);
+        assert_eq!(Some(92), too_recent.wait_seconds);
+
+        assert_eq!(
+            Some(45),
+            response("You gave an answer too recently. You have 45s left to wait.").wait_seconds
+        );
+
+        assert_eq!(
+            Outcome::WrongLevel,
+            response("You don't seem to be solving the right level.  Did you already complete it?")
+                .outcome
+        );
+        assert_eq!(Outcome::Unknown, response("Something else").outcome);
+    }
+
+    #[test]
+    fn refuse_submissions() {
+        let mut log = SubmissionLog::default();
+        assert!(log.check(9, 1, "6000", 0).is_ok());
+
+        log.record(9, 1, "6000", &response("That's not the right answer; your answer is too high. Please wait one minute before trying again."), 0);
+        log.record(
+            9,
+            1,
+            "10",
+            &response("That's not the right answer; your answer is too low."),
+            100,
+        );
+        log.record(9, 1, "abc", &response("That's not the right answer."), 100);
+
+        assert!(log.check(9, 1, "5000", 30).is_err());
+        assert!(log.check(9, 1, "5000", 100).is_ok());
+        assert!(log.check(9, 1, "6000", 100).is_err());
+        assert!(log.check(9, 1, "7000", 100).is_err());
+        assert!(log.check(9, 1, "10", 100).is_err());
+        assert!(log.check(9, 1, "9", 100).is_err());
+        assert!(log.check(9, 1, "abc", 100).is_err());
+        assert!(log.check(9, 2, "6000", 100).is_ok());
+
+        log.record(9, 1, "5000", &response("That's the right answer!"), 100);
+        assert!(log.check(9, 1, "4000", 100).is_err());
+
+        let saved = toml::to_string(&log).unwrap();
+        assert_eq!(
+            log.submissions,
+            toml::from_str::<SubmissionLog>(&saved).unwrap().submissions
+        );
+    }
+
+    #[test]
+    fn submit_answer() {
+        let (base_url, handle) = serve_once(
+            "200 OK",
+            "<html><article><p>That's the right answer!</p></article></html>",
+        );
+
+        let client = Client::new(&Config {
+            session: Some("abc123".to_string()),
+            base_url,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let html = client.submit_answer(2022, 9, 2, "2607").unwrap();
+        let request = handle.join().unwrap();
+
+        assert_eq!(Outcome::Correct, Response::parse(&html).outcome);
+        assert!(request.starts_with("POST /2022/day/9/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=2607"));
+    }
+}
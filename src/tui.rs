@@ -0,0 +1,458 @@
+use std::collections::HashMap;
+use std::io;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::crossterm::terminal;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState, Tabs, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::answers::Verdict;
+use crate::calendar::unix_time;
+use crate::config::Config;
+use crate::isolate::set_interrupt_cleanup;
+use crate::report::{format_millis, format_part_cell, DayReport, Status};
+use crate::Day;
+use crate::Year;
+
+/// What a key press asks the menu to do
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    None,
+    Run,
+    RunAll,
+    Quit,
+}
+
+/// A day's completion status, from the last time it was run
+fn get_marker(report: Option<&DayReport>) -> (&'static str, Color) {
+    let Some(report) = report else {
+        return (" ", Color::Reset);
+    };
+
+    match &report.status {
+        Status::Completed(_) if report.has_failed() => ("!", Color::Red),
+        Status::Completed(_)
+            if report
+                .verdicts()
+                .iter()
+                .all(|v| *v == Some(Verdict::Correct)) =>
+        {
+            ("✔", Color::Green)
+        }
+        Status::Completed(_) if report.has_wrong_answer() => ("✘", Color::Red),
+        Status::Completed(_) => ("?", Color::Yellow),
+        Status::Skipped(_) => ("-", Color::DarkGray),
+        Status::Failed(_) => ("!", Color::Red),
+    }
+}
+
+/// The full-screen menu for picking and running days
+struct App<'a> {
+    years: Vec<Year>,
+    year_index: usize,
+    search: String,
+    table: TableState,
+    /// The last result of every day that's been run, by year and day
+    results: HashMap<(u32, usize), DayReport>,
+    running: Option<String>,
+    config: &'a Config,
+}
+
+impl<'a> App<'a> {
+    /// Fails if there aren't any days to pick from
+    fn new(config: &'a Config) -> io::Result<Self> {
+        let years = Year::create_years();
+
+        if years.is_empty() {
+            return Err(io::Error::other(
+                "There aren't any days yet! Add one with \"advent_of_code new <YEAR> <DAY> <TITLE>\"",
+            ));
+        }
+
+        let mut app = Self {
+            years,
+            year_index: 0,
+            search: String::new(),
+            table: TableState::default(),
+            results: HashMap::new(),
+            running: None,
+            config,
+        };
+
+        // Start on today's puzzle (or the latest day), since that's usually the one being worked on
+        let now = unix_time();
+        let year = Year::get_current_year(&app.years, now).ok();
+
+        app.year_index = app
+            .years
+            .iter()
+            .position(|other| Some(other.year) == year.map(|year| year.year))
+            .unwrap_or(app.years.len().saturating_sub(1));
+
+        let today = app.year().get_today(now).ok().map(|day| day.day);
+        let selected = app
+            .year()
+            .days
+            .iter()
+            .position(|day| Some(day.day) == today);
+
+        app.table.select(selected);
+        app.clamp_selection();
+        Ok(app)
+    }
+
+    fn year(&self) -> &Year {
+        &self.years[self.year_index]
+    }
+
+    /// The days in the selected year whose title (or number) matches the search
+    fn get_visible_days(&self) -> Vec<&'static Day> {
+        let search = self.search.to_lowercase();
+
+        self.year()
+            .days
+            .iter()
+            .filter(|day| {
+                day.name.to_lowercase().contains(&search) || day.day.to_string() == search
+            })
+            .copied()
+            .collect()
+    }
+
+    fn get_selected_day(&self) -> Option<&'static Day> {
+        self.get_visible_days().get(self.table.selected()?).copied()
+    }
+
+    /// Keeps the selection on one of the visible days, e.g after the search has changed
+    fn clamp_selection(&mut self) {
+        let visible = self.get_visible_days().len();
+
+        if visible == 0 {
+            self.table.select(None);
+        } else {
+            let selected = self.table.selected().unwrap_or(0).min(visible - 1);
+            self.table.select(Some(selected));
+        }
+    }
+
+    fn select_year(&mut self, year_index: usize) {
+        self.year_index = year_index.min(self.years.len() - 1);
+        self.clamp_selection();
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return match key.code {
+                KeyCode::Char('c') => Action::Quit,
+                KeyCode::Char('a') => Action::RunAll,
+                _ => Action::None,
+            };
+        }
+
+        match key.code {
+            KeyCode::Esc if self.search.is_empty() => return Action::Quit,
+            KeyCode::Esc => {
+                self.search.clear();
+                self.clamp_selection();
+            }
+            KeyCode::Enter if self.get_selected_day().is_some() => return Action::Run,
+            KeyCode::Up => {
+                let selected = self.table.selected().unwrap_or(0);
+                self.table.select(Some(selected.saturating_sub(1)));
+            }
+            KeyCode::Down => {
+                let selected = self.table.selected().map_or(0, |selected| selected + 1);
+                self.table.select(Some(selected));
+                self.clamp_selection();
+            }
+            KeyCode::Home => {
+                self.table.select(Some(0));
+                self.clamp_selection();
+            }
+            KeyCode::End => {
+                self.table.select(Some(usize::MAX));
+                self.clamp_selection();
+            }
+            KeyCode::Left | KeyCode::BackTab => {
+                self.select_year(self.year_index.saturating_sub(1));
+            }
+            KeyCode::Right | KeyCode::Tab => self.select_year(self.year_index + 1),
+            KeyCode::Backspace => {
+                self.search.pop();
+                self.clamp_selection();
+            }
+            KeyCode::Char(c) => {
+                self.search.push(c);
+                self.table.select(Some(0));
+                self.clamp_selection();
+            }
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    fn run_selected(&mut self) {
+        let Some(day) = self.get_selected_day() else {
+            return;
+        };
+
+        let report = self.year().report_day(day, None, self.config);
+        self.results.insert((report.year, report.day), report);
+    }
+
+    /// Runs every day in the selected year that has an input
+    fn run_all(&mut self) {
+        for report in self.year().report_days(self.config) {
+            self.results.insert((report.year, report.day), report);
+        }
+    }
+
+    fn get_result(&self, day: &Day) -> Option<&DayReport> {
+        self.results.get(&(day.year, day.day))
+    }
+
+    fn render(&mut self, frame: &mut Frame) {
+        let [tabs_area, days_area, result_area, help_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(5),
+            Constraint::Length(12),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let tabs = Tabs::new(self.years.iter().map(|year| year.year.to_string()))
+            .select(self.year_index)
+            .highlight_style(Style::new().add_modifier(Modifier::BOLD | Modifier::REVERSED))
+            .block(Block::bordered().title(" Bobby Shmurner's Advent Of Code "));
+        frame.render_widget(tabs, tabs_area);
+
+        let days = self.get_visible_days();
+        let rows = days.iter().map(|day| {
+            let result = self.get_result(day);
+            let (marker, color) = get_marker(result);
+
+            let (part_1, part_2, time) = match result.map(|result| &result.status) {
+                Some(Status::Completed(answers)) => (
+                    format_part_cell(&answers.part_1),
+                    format_part_cell(&answers.part_2),
+                    format_millis(answers.timings.total()),
+                ),
+                _ => Default::default(),
+            };
+
+            Row::new([
+                Line::styled(marker, Style::new().fg(color)),
+                Line::from(day.day.to_string()).right_aligned(),
+                Line::from(day.name),
+                Line::from(part_1),
+                Line::from(part_2),
+                Line::from(time).right_aligned(),
+            ])
+        });
+
+        let widths = [
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(12),
+        ];
+
+        let title = match days.len() {
+            0 => format!(" No Days Match \"{}\" ", self.search),
+            _ => format!(" Days For {} ", self.year().year),
+        };
+
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(["", "Day", "Name", "Part 1", "Part 2", "Time"])
+                    .style(Style::new().add_modifier(Modifier::BOLD)),
+            )
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .block(Block::bordered().title(title));
+        frame.render_stateful_widget(table, days_area, &mut self.table);
+
+        let (title, text) = match self.get_selected_day() {
+            Some(day) => {
+                let text = match (&self.running, self.get_result(day)) {
+                    (Some(running), _) => running.clone(),
+                    (None, Some(result)) if result.input_changes.is_empty() => result.to_text(),
+                    (None, Some(result)) => format!(
+                        "Normalized the input: {}\n\n{}",
+                        result.input_changes.join(", "),
+                        result.to_text()
+                    ),
+                    (None, None) => "Press Enter to run this day".to_string(),
+                };
+
+                (format!(" Day {}: {} ", day.day, day.name), text)
+            }
+            None => (String::new(), String::new()),
+        };
+
+        let result = Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .block(Block::bordered().title(title));
+        frame.render_widget(result, result_area);
+
+        let help = if self.search.is_empty() {
+            "Type to search · ↑↓ Day · ←→ Year · Enter Run · Ctrl-A Run All · Esc Quit".to_string()
+        } else {
+            format!("Search: {}▏ · ↑↓ Day · Enter Run · Esc Clear", self.search)
+        };
+        frame.render_widget(
+            Line::from(help).style(Style::new().fg(Color::DarkGray)),
+            help_area,
+        );
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.render(frame))?;
+
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            let action = self.handle_key(key);
+
+            match action {
+                Action::None => continue,
+                Action::Quit => return Ok(()),
+                Action::Run => self.running = Some("Running... (Ctrl-C to cancel)".to_string()),
+                Action::RunAll => {
+                    self.running = Some(format!(
+                        "Running every day in {}... (Ctrl-C to cancel the current day)",
+                        self.year().year
+                    ))
+                }
+            }
+
+            terminal.draw(|frame| self.render(frame))?;
+
+            // In raw mode, Ctrl-C is just a key press. It's turned off while days run, so that Ctrl-C cancels
+            // the day that's running instead
+            terminal::disable_raw_mode()?;
+
+            match action {
+                Action::RunAll => self.run_all(),
+                _ => self.run_selected(),
+            }
+
+            terminal::enable_raw_mode()?;
+
+            self.running = None;
+
+            // Anything that was printed while the day ran (such as warnings) would otherwise be left on screen
+            terminal.clear()?;
+        }
+    }
+}
+
+/// Opens the full-screen menu, and keeps it open until it's closed with Esc or Ctrl-C
+pub fn run(config: &Config) -> io::Result<()> {
+    let mut app = App::new(config)?;
+    let mut terminal = ratatui::try_init()?;
+    set_interrupt_cleanup(Some(ratatui::restore));
+    let result = app.run(&mut terminal);
+
+    set_interrupt_cleanup(None);
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::backend::TestBackend;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::Terminal;
+
+    use super::{Action, App};
+    use crate::config::Config;
+
+    fn press(app: &mut App, code: KeyCode) -> Action {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn type_search(app: &mut App, search: &str) {
+        for c in search.chars() {
+            press(app, KeyCode::Char(c));
+        }
+    }
+
+    #[test]
+    fn search_and_select() {
+        let config = Config::default();
+        let mut app = App::new(&config).unwrap();
+
+        press(&mut app, KeyCode::Home);
+        assert_eq!(Some(1), app.get_selected_day().map(|day| day.day));
+
+        press(&mut app, KeyCode::Up);
+        assert_eq!(Some(1), app.get_selected_day().map(|day| day.day));
+
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(Some(3), app.get_selected_day().map(|day| day.day));
+
+        press(&mut app, KeyCode::End);
+        assert_eq!(
+            app.year().days.last().map(|day| day.day),
+            app.get_selected_day().map(|day| day.day)
+        );
+
+        type_search(&mut app, "ROPE");
+        assert_eq!(Some(9), app.get_selected_day().map(|day| day.day));
+        assert_eq!(Action::Run, press(&mut app, KeyCode::Enter));
+
+        type_search(&mut app, "x");
+        assert_eq!(None, app.get_selected_day().map(|day| day.day));
+        assert_eq!(Action::None, press(&mut app, KeyCode::Enter));
+
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(Some(9), app.get_selected_day().map(|day| day.day));
+
+        assert_eq!(Action::None, press(&mut app, KeyCode::Esc));
+        assert_eq!(Action::Quit, press(&mut app, KeyCode::Esc));
+
+        type_search(&mut app, "12");
+        assert_eq!(Some(12), app.get_selected_day().map(|day| day.day));
+
+        assert_eq!(
+            Action::Quit,
+            app.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL))
+        );
+    }
+
+    #[test]
+    fn render() {
+        let config = Config::default();
+        let mut app = App::new(&config).unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+
+        type_search(&mut app, "rope");
+        terminal.draw(|frame| app.render(frame)).unwrap();
+
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(screen.contains("Rope Bridge"));
+        assert!(!screen.contains("Calorie Counting"));
+        assert!(screen.contains("Press Enter to run this day"));
+        assert!(screen.contains("Search: rope"));
+    }
+}
@@ -0,0 +1,183 @@
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::config::Config;
+use crate::report::{print_rows, DayReport};
+use crate::Day;
+use crate::Year;
+
+/// When each watched file was last modified, and how long it was. `None` if the file doesn't exist
+#[derive(PartialEq, Eq, Debug)]
+struct Snapshot {
+    files: Vec<(PathBuf, Option<(SystemTime, u64)>)>,
+}
+
+impl Snapshot {
+    fn take(paths: Vec<PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = fs::metadata(&path)
+                    .ok()
+                    .and_then(|metadata| Some((metadata.modified().ok()?, metadata.len())));
+
+                (path, modified)
+            })
+            .collect();
+
+        Self { files }
+    }
+
+    /// The files that were created, changed, or deleted since the `older` snapshot
+    fn changes(&self, older: &Snapshot) -> Vec<PathBuf> {
+        self.files
+            .iter()
+            .filter(|file| !older.files.contains(file))
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+/// The examples that a day has, numbered from 1. Examples are numbered in order, so this stops at the first
+/// one that doesn't exist
+fn get_example_indices(year: u32, day_num: usize, config: &Config) -> Vec<usize> {
+    (1..)
+        .take_while(|index| config.get_example_path(year, day_num, *index).exists())
+        .collect()
+}
+
+/// Reruns a day whenever its input, its examples, or their known answers change
+pub(crate) struct Watcher<'a> {
+    year: &'a Year,
+    day: &'a Day,
+    config: &'a Config,
+    interval: Duration,
+    snapshot: Snapshot,
+}
+
+impl<'a> Watcher<'a> {
+    pub fn new(year: &'a Year, day: &'a Day, config: &'a Config, interval: Duration) -> Self {
+        Self {
+            year,
+            day,
+            config,
+            interval,
+            snapshot: Snapshot { files: Vec::new() },
+        }
+    }
+
+    /// Every file that affects the day's results. The next example (which doesn't exist yet) is watched too,
+    /// so that adding it causes a rerun
+    fn get_watched_paths(&self) -> Vec<PathBuf> {
+        let year = self.year.year;
+        let day_num = self.day.day;
+        let examples = get_example_indices(year, day_num, self.config);
+
+        let mut paths = vec![
+            self.config.get_input_path(year, day_num),
+            self.config.get_answers_path(year),
+        ];
+
+        for index in examples.iter().copied().chain([examples.len() + 1]) {
+            paths.push(self.config.get_example_path(year, day_num, index));
+            paths.push(self.config.get_example_answers_path(year, day_num, index));
+        }
+
+        paths
+    }
+
+    /// Runs the day against its input and each of its examples. The files are only looked at again after the
+    /// run, since running the day creates its input file if it's missing
+    pub fn run(&mut self) -> Vec<DayReport> {
+        let mut reports = vec![self.year.report_day(self.day, None, self.config)];
+
+        for index in get_example_indices(self.year.year, self.day.day, self.config) {
+            reports.push(self.year.report_example(self.day, index, self.config));
+        }
+
+        self.snapshot = Snapshot::take(self.get_watched_paths());
+        reports
+    }
+
+    /// Blocks until any of the watched files change, returning the ones that did
+    pub fn wait_for_change(&mut self) -> Vec<PathBuf> {
+        loop {
+            thread::sleep(self.interval);
+
+            let snapshot = Snapshot::take(self.get_watched_paths());
+            let changes = snapshot.changes(&self.snapshot);
+            self.snapshot = snapshot;
+
+            if !changes.is_empty() {
+                return changes;
+            }
+        }
+    }
+}
+
+/// Prints the latest results next to the ones from the run before, matching them up by their input
+pub(crate) fn print_watch_table(reports: &[DayReport], previous: &[DayReport]) {
+    const HEADERS: [&str; 8] = [
+        "Input",
+        "Part 1",
+        "Part 2",
+        "Total Time",
+        "Status",
+        "Previous Part 1",
+        "Previous Part 2",
+        "Previous Time",
+    ];
+
+    let rows: Vec<[String; 8]> = reports
+        .iter()
+        .map(|report| {
+            let [_, _, _, part_1, part_2, _, _, _, total, status] = report.get_row();
+            let [_, _, _, previous_1, previous_2, _, _, _, previous_total, _] = previous
+                .iter()
+                .find(|previous| previous.input_path == report.input_path)
+                .map_or_else(Default::default, DayReport::get_row);
+
+            [
+                report.get_input_label(),
+                part_1,
+                part_2,
+                total,
+                status,
+                previous_1,
+                previous_2,
+                previous_total,
+            ]
+        })
+        .collect();
+
+    print_rows(&HEADERS, &rows);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::Snapshot;
+
+    #[test]
+    fn snapshot_changes() {
+        let folder = env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        fs::create_dir_all(&folder).unwrap();
+
+        let input = folder.join("day_14.txt");
+        let example = folder.join("day_14.example.txt");
+        fs::write(&input, "498,4 -> 498,6\n").unwrap();
+
+        let paths = vec![input.clone(), example.clone()];
+        let before = Snapshot::take(paths.clone());
+        assert!(Snapshot::take(paths.clone()).changes(&before).is_empty());
+
+        fs::write(&input, "498,4 -> 498,6 -> 496,6\n").unwrap();
+        fs::write(&example, "503,4 -> 502,4\n").unwrap();
+        assert_eq!(paths, Snapshot::take(paths.clone()).changes(&before));
+
+        fs::remove_dir_all(folder).unwrap();
+    }
+}
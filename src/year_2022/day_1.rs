@@ -1,9 +1,9 @@
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
 #[derive(Debug)]
-struct Elf {
+pub struct Elf {
     calories: u32,
 }
 
@@ -28,80 +28,65 @@ impl Elf {
     }
 }
 
-pub fn execute(input: &str) -> DayReturnType {
-    let mut top_three_elfs: Vec<Elf> = Vec::new();
+fn get_top_calories(elves: &[Elf], count: usize) -> u32 {
+    let mut top_elves: Vec<&Elf> = Vec::new();
 
-    for elf_data in input.split("\n\n") {
-        let new_elf = unwrap_or_return!(Elf::new(elf_data));
+    for new_elf in elves {
+        let position = top_elves
+            .iter()
+            .position(|elf| new_elf.calories > elf.calories)
+            .unwrap_or(top_elves.len());
 
-        if top_three_elfs.is_empty() {
-            top_three_elfs.push(new_elf);
-            continue;
-        }
+        top_elves.insert(position, new_elf);
+        top_elves.truncate(count);
+    }
 
-        for (i, elf) in top_three_elfs.iter().enumerate() {
-            if top_three_elfs.len() < 3 || new_elf.calories > elf.calories {
-                top_three_elfs.insert(i, new_elf);
-                break;
-            }
-        }
+    top_elves.iter().map(|elf| elf.calories).sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Elf>;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-        if top_three_elfs.len() > 3 {
-            top_three_elfs.pop();
+    fn parse(input: &str) -> Result<Vec<Elf>, BoxedError> {
+        let mut elves = Vec::new();
+
+        for elf_data in input.trim().split("\n\n") {
+            elves.push(unwrap_or_return!(Elf::new(elf_data)));
         }
-    }
 
-    let most_calories = top_three_elfs[0].calories;
-    let mut top_three_calories = 0;
+        Ok(elves)
+    }
 
-    for elf in top_three_elfs {
-        top_three_calories += elf.calories;
+    fn part_1(elves: &Vec<Elf>) -> Result<u32, BoxedError> {
+        Ok(get_top_calories(elves, 1))
     }
 
-    Ok((most_calories.to_string(), top_three_calories.to_string()))
+    fn part_2(elves: &Vec<Elf>) -> Result<u32, BoxedError> {
+        Ok(get_top_calories(elves, 3))
+    }
 }
 
+register_day!(2022, 1, "Calorie Counting", Day1);
+
 #[cfg(test)]
 mod tests {
+    use super::Day1;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day1::execute(example!(2022, 1)).unwrap().part_1.unwrap();
         assert_eq!("24000", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000"#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = Day1::execute(example!(2022, 1)).unwrap().part_2.unwrap();
         assert_eq!("45000", result);
     }
 }
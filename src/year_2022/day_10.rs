@@ -1,14 +1,23 @@
+use crate::error::Locate;
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
-trait Operation {
+pub trait Operation {
     fn tick(&mut self, cpu: &mut Cpu) -> bool;
+    fn clone_box(&self) -> Box<dyn Operation>;
     fn new(operand: &str) -> Result<Box<Self>, BoxedError>
     where
         Self: Sized;
 }
 
+impl Clone for Box<dyn Operation> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+#[derive(Clone)]
 struct NoOp {}
 
 impl Operation for NoOp {
@@ -16,6 +25,10 @@ impl Operation for NoOp {
         true
     }
 
+    fn clone_box(&self) -> Box<dyn Operation> {
+        Box::new(self.clone())
+    }
+
     fn new(_operand: &str) -> Result<Box<Self>, BoxedError>
     where
         Self: Sized,
@@ -24,6 +37,7 @@ impl Operation for NoOp {
     }
 }
 
+#[derive(Clone)]
 struct Add {
     cycles_remaining: u32,
     operand: i32,
@@ -41,6 +55,10 @@ impl Operation for Add {
         true
     }
 
+    fn clone_box(&self) -> Box<dyn Operation> {
+        Box::new(self.clone())
+    }
+
     fn new(operand: &str) -> Result<Box<Self>, BoxedError>
     where
         Self: Sized,
@@ -58,7 +76,7 @@ impl Operation for Add {
     }
 }
 
-struct Cpu {
+pub struct Cpu {
     operations: Option<Vec<Box<dyn Operation>>>,
     cycle: i32,
     cycle_until_next_strength_update: u32,
@@ -146,340 +164,63 @@ fn parse_operation(input: &str) -> Result<Box<dyn Operation>, BoxedError> {
     })
 }
 
-fn parse_operations(input: &str) -> Result<Vec<Box<dyn Operation>>, BoxedError> {
-    let mut operations = Vec::new();
+pub struct Day10;
 
-    for line in input.lines() {
-        operations.push(parse_operation(line)?);
-    }
+impl Solution for Day10 {
+    type Input = Vec<Box<dyn Operation>>;
+    type Answer1 = i32;
+    type Answer2 = String;
 
-    Ok(operations)
-}
+    fn parse(input: &str) -> Result<Vec<Box<dyn Operation>>, BoxedError> {
+        let mut operations = Vec::new();
 
-pub fn execute(input: &str) -> DayReturnType {
-    let operations = parse_operations(input)?;
-    let mut cpu = Cpu::new(operations);
+        for (index, line) in input.lines().enumerate() {
+            operations.push(parse_operation(line).at_line(index + 1)?);
+        }
 
-    for _i in 0..220 {
-        cpu.tick()?;
+        Ok(operations)
     }
 
-    let signal_strnegth = cpu.total_signal_strength;
+    fn part_1(operations: &Vec<Box<dyn Operation>>) -> Result<i32, BoxedError> {
+        let mut cpu = Cpu::new(operations.to_vec());
+
+        for _i in 0..220 {
+            cpu.tick()?;
+        }
 
-    for _i in 0..20 {
-        cpu.tick()?;
+        Ok(cpu.total_signal_strength)
     }
 
-    let display = cpu.display.trim();
+    fn part_2(operations: &Vec<Box<dyn Operation>>) -> Result<String, BoxedError> {
+        let mut cpu = Cpu::new(operations.to_vec());
+
+        for _i in 0..240 {
+            cpu.tick()?;
+        }
 
-    Ok((signal_strnegth.to_string(), "\n\n".to_string() + display))
+        let display = cpu.display.trim();
+
+        Ok("\n\n".to_string() + display)
+    }
 }
 
+register_day!(2022, 10, "Cathode-Ray Tube", Day10);
+
 #[cfg(test)]
 mod tests {
+    use super::Day10;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"addx 15
-addx -11
-addx 6
-addx -3
-addx 5
-addx -1
-addx -8
-addx 13
-addx 4
-noop
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx -35
-addx 1
-addx 24
-addx -19
-addx 1
-addx 16
-addx -11
-noop
-noop
-addx 21
-addx -15
-noop
-noop
-addx -3
-addx 9
-addx 1
-addx -3
-addx 8
-addx 1
-addx 5
-noop
-noop
-noop
-noop
-noop
-addx -36
-noop
-addx 1
-addx 7
-noop
-noop
-noop
-addx 2
-addx 6
-noop
-noop
-noop
-noop
-noop
-addx 1
-noop
-noop
-addx 7
-addx 1
-noop
-addx -13
-addx 13
-addx 7
-noop
-addx 1
-addx -33
-noop
-noop
-noop
-addx 2
-noop
-noop
-noop
-addx 8
-noop
-addx -1
-addx 2
-addx 1
-noop
-addx 17
-addx -9
-addx 1
-addx 1
-addx -3
-addx 11
-noop
-noop
-addx 1
-noop
-addx 1
-noop
-noop
-addx -13
-addx -19
-addx 1
-addx 3
-addx 26
-addx -30
-addx 12
-addx -1
-addx 3
-addx 1
-noop
-noop
-noop
-addx -9
-addx 18
-addx 1
-addx 2
-noop
-noop
-addx 9
-noop
-noop
-noop
-addx -1
-addx 2
-addx -37
-addx 1
-addx 3
-noop
-addx 15
-addx -21
-addx 22
-addx -6
-addx 1
-noop
-addx 2
-addx 1
-noop
-addx -10
-noop
-noop
-addx 20
-addx 1
-addx 2
-addx 2
-addx -6
-addx -11
-noop
-noop
-noop"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day10::execute(example!(2022, 10)).unwrap().part_1.unwrap();
         assert_eq!("13140", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"addx 15
-addx -11
-addx 6
-addx -3
-addx 5
-addx -1
-addx -8
-addx 13
-addx 4
-noop
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx -35
-addx 1
-addx 24
-addx -19
-addx 1
-addx 16
-addx -11
-noop
-noop
-addx 21
-addx -15
-noop
-noop
-addx -3
-addx 9
-addx 1
-addx -3
-addx 8
-addx 1
-addx 5
-noop
-noop
-noop
-noop
-noop
-addx -36
-noop
-addx 1
-addx 7
-noop
-noop
-noop
-addx 2
-addx 6
-noop
-noop
-noop
-noop
-noop
-addx 1
-noop
-noop
-addx 7
-addx 1
-noop
-addx -13
-addx 13
-addx 7
-noop
-addx 1
-addx -33
-noop
-noop
-noop
-addx 2
-noop
-noop
-noop
-addx 8
-noop
-addx -1
-addx 2
-addx 1
-noop
-addx 17
-addx -9
-addx 1
-addx 1
-addx -3
-addx 11
-noop
-noop
-addx 1
-noop
-addx 1
-noop
-noop
-addx -13
-addx -19
-addx 1
-addx 3
-addx 26
-addx -30
-addx 12
-addx -1
-addx 3
-addx 1
-noop
-noop
-noop
-addx -9
-addx 18
-addx 1
-addx 2
-noop
-noop
-addx 9
-noop
-noop
-noop
-addx -1
-addx 2
-addx -37
-addx 1
-addx 3
-noop
-addx 15
-addx -21
-addx 22
-addx -6
-addx 1
-noop
-addx 2
-addx 1
-noop
-addx -10
-noop
-noop
-addx 20
-addx 1
-addx 2
-addx 2
-addx -6
-addx -11
-noop
-noop
-noop"#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = Day10::execute(example!(2022, 10)).unwrap().part_2.unwrap();
         assert_eq!(
             r#"
 
@@ -1,6 +1,7 @@
+use crate::isolate::check_cancelled;
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
 use regex::Regex;
 
@@ -158,109 +159,80 @@ impl Monkey {
     }
 }
 
-pub fn execute(input: &str) -> DayReturnType {
-    let re = Regex::new(r"Monkey (?P<monkey_num>\d+):\n  Starting items: (?P<starting_items>(?:\d+, )*\d+)\n  Operation: new = old (?P<operator>[+*]) (?P<operand>(?:\d+|old))\n  Test: divisible by (?P<test>\d+)\n    If true: throw to monkey (?P<true_monkey>\d+)\n    If false: throw to monkey (?P<false_monkey>\d+)").unwrap();
+pub struct Troop {
+    monkeys: Vec<Monkey>,
+    lcm: u128,
+}
 
-    let mut monkeys = Vec::new();
-    let mut lcm = 1;
+impl Troop {
+    fn get_monkey_business(&self, rounds: usize, managed: bool) -> Result<u128, BoxedError> {
+        let mut monkeys = self.monkeys.clone();
 
-    for caps in re.captures_iter(input) {
-        let monkey = Monkey::new(
-            &caps["starting_items"],
-            &caps["operator"],
-            &caps["operand"],
-            &caps["test"],
-            &caps["true_monkey"],
-            &caps["false_monkey"],
-        )?;
+        for _i in 0..rounds {
+            check_cancelled()?;
+            Monkey::complete_round(&mut monkeys, managed, self.lcm)?;
+        }
 
-        lcm *= monkey.test;
-        monkeys.push(monkey);
+        Monkey::get_monkey_business(&monkeys)
     }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Troop;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Troop, BoxedError> {
+        let re = Regex::new(r"Monkey (?P<monkey_num>\d+):\n  Starting items: (?P<starting_items>(?:\d+, )*\d+)\n  Operation: new = old (?P<operator>[+*]) (?P<operand>(?:\d+|old))\n  Test: divisible by (?P<test>\d+)\n    If true: throw to monkey (?P<true_monkey>\d+)\n    If false: throw to monkey (?P<false_monkey>\d+)").unwrap();
 
-    let mut monkeys_unmanaged = monkeys.clone();
+        let mut monkeys = Vec::new();
+        let mut lcm = 1;
 
-    for _i in 0..20 {
-        Monkey::complete_round(&mut monkeys, true, lcm)?;
+        for caps in re.captures_iter(input) {
+            let monkey = Monkey::new(
+                &caps["starting_items"],
+                &caps["operator"],
+                &caps["operand"],
+                &caps["test"],
+                &caps["true_monkey"],
+                &caps["false_monkey"],
+            )?;
+
+            lcm *= monkey.test;
+            monkeys.push(monkey);
+        }
+
+        Ok(Troop { monkeys, lcm })
     }
 
-    for _i in 0..10000 {
-        Monkey::complete_round(&mut monkeys_unmanaged, false, lcm)?;
+    fn part_1(troop: &Troop) -> Result<u128, BoxedError> {
+        troop.get_monkey_business(20, true)
     }
 
-    Ok((
-        Monkey::get_monkey_business(&monkeys)?.to_string(),
-        Monkey::get_monkey_business(&monkeys_unmanaged)?.to_string(),
-    ))
+    fn part_2(troop: &Troop) -> Result<u128, BoxedError> {
+        troop.get_monkey_business(10000, false)
+    }
 }
 
+register_day!(2022, 11, "Monkey in the Middle", Day11);
+
 #[cfg(test)]
 mod tests {
+    use super::Day11;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"Monkey 0:
-  Starting items: 79, 98
-  Operation: new = old * 19
-  Test: divisible by 23
-    If true: throw to monkey 2
-    If false: throw to monkey 3
-
-Monkey 1:
-  Starting items: 54, 65, 75, 74
-  Operation: new = old + 6
-  Test: divisible by 19
-    If true: throw to monkey 2
-    If false: throw to monkey 0
-
-Monkey 2:
-  Starting items: 79, 60, 97
-  Operation: new = old * old
-  Test: divisible by 13
-    If true: throw to monkey 1
-    If false: throw to monkey 3
-
-Monkey 3:
-  Starting items: 74
-  Operation: new = old + 3
-  Test: divisible by 17
-    If true: throw to monkey 0
-    If false: throw to monkey 1"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day11::execute(example!(2022, 11)).unwrap().part_1.unwrap();
         assert_eq!("10605", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"Monkey 0:
-  Starting items: 79, 98
-  Operation: new = old * 19
-  Test: divisible by 23
-    If true: throw to monkey 2
-    If false: throw to monkey 3
-
-Monkey 1:
-  Starting items: 54, 65, 75, 74
-  Operation: new = old + 6
-  Test: divisible by 19
-    If true: throw to monkey 2
-    If false: throw to monkey 0
-
-Monkey 2:
-  Starting items: 79, 60, 97
-  Operation: new = old * old
-  Test: divisible by 13
-    If true: throw to monkey 1
-    If false: throw to monkey 3
-
-Monkey 3:
-  Starting items: 74
-  Operation: new = old + 3
-  Test: divisible by 17
-    If true: throw to monkey 0
-    If false: throw to monkey 1"#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = Day11::execute(example!(2022, 11)).unwrap().part_2.unwrap();
         assert_eq!("2713310158", result);
     }
 }
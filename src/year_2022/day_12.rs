@@ -1,11 +1,11 @@
-use crate::clear;
+use crate::isolate::check_cancelled;
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
 use std::collections::HashMap;
 
-struct Grid {
+pub struct Grid {
     start_points: Vec<(usize, usize)>,
     elevations: Vec<Vec<u8>>,
     width: usize,
@@ -45,7 +45,7 @@ impl Grid {
                 }
 
                 if !elevation.is_ascii_lowercase() {
-                    return_err!("Invalid char \'{}\' at position ({}, {})", elevation, x, y);
+                    return_err!(parse(y + 1, x + 1): "Invalid char \'{}\'", elevation);
                 }
 
                 row_elevations.push((elevation as u8) - 97);
@@ -95,14 +95,14 @@ impl Grid {
         valid_moves
     }
 
-    fn get_shortest_paths(&self) -> (usize, usize) {
+    fn get_shortest_paths(&self) -> Result<(usize, usize), BoxedError> {
         let mut queue = vec![self.end];
         let mut visited = HashMap::new();
 
         visited.insert(self.end, 0_usize);
 
-        while !queue.is_empty() {
-            let pos = queue.pop().unwrap();
+        while let Some(pos) = queue.pop() {
+            check_cancelled()?;
             let dist = visited[&pos];
 
             for valid_move in self.get_valid_moves(&pos) {
@@ -124,45 +124,51 @@ impl Grid {
             }
         }
 
-        (dist_from_start, shortest_dist)
+        Ok((dist_from_start, shortest_dist))
     }
 }
 
-pub fn execute(input: &str) -> DayReturnType {
-    let grid = Grid::new(input)?;
+pub struct Day12;
 
-    let (shortest_from_start, shortest_overall) = grid.get_shortest_paths();
-    clear().unwrap();
+impl Solution for Day12 {
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    Ok((
-        shortest_from_start.to_string(),
-        shortest_overall.to_string(),
-    ))
+    fn parse(input: &str) -> Result<Grid, BoxedError> {
+        Grid::new(input)
+    }
+
+    fn part_1(grid: &Grid) -> Result<usize, BoxedError> {
+        let (shortest_from_start, _) = grid.get_shortest_paths()?;
+
+        Ok(shortest_from_start)
+    }
+
+    fn part_2(grid: &Grid) -> Result<usize, BoxedError> {
+        let (_, shortest_overall) = grid.get_shortest_paths()?;
+
+        Ok(shortest_overall)
+    }
 }
 
+register_day!(2022, 12, "Hill Climbing Algorithm", Day12);
+
 #[cfg(test)]
 mod tests {
+    use super::Day12;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"Sabqponm
-abcryxxl
-accszExk
-acctuvwj
-abdefghi"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day12::execute(example!(2022, 12)).unwrap().part_1.unwrap();
         assert_eq!("31", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"Sabqponm
-abcryxxl
-accszExk
-acctuvwj
-abdefghi"#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = Day12::execute(example!(2022, 12)).unwrap().part_2.unwrap();
         assert_eq!("29", result);
     }
 }
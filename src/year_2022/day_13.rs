@@ -1,11 +1,13 @@
+use crate::error::{offset_in, Locate};
+use crate::isolate::check_cancelled;
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
 use std::cmp::*;
 
 #[derive(Clone, Eq, PartialEq)]
-struct PacketInfo {
+pub struct PacketInfo {
     packets: Vec<Packet>,
     input_str: String,
     stripped_str: String,
@@ -115,6 +117,7 @@ impl Packet {
         Ok(Packet::Packet(packet))
     }
 
+    #[allow(clippy::ptr_arg)]
     fn compare(left: &Vec<Packet>, right: &Vec<Packet>) -> Option<Ordering> {
         let mut i: i32 = -1;
 
@@ -177,109 +180,92 @@ impl Packet {
     }
 }
 
-pub fn execute(input: &str) -> DayReturnType {
-    let mut correct_order: u32 = 0;
-    let mut packets = Vec::new();
+pub struct Day13;
 
-    for (i, pair) in input
-        .trim()
-        .split("\n\n")
-        .collect::<Vec<&str>>()
-        .iter()
-        .enumerate()
-    {
-        let pair: Vec<&str> = pair.trim().lines().collect();
+impl Solution for Day13 {
+    type Input = Vec<(PacketInfo, PacketInfo)>;
+    type Answer1 = u32;
+    type Answer2 = usize;
 
-        let left = PacketInfo::parse(pair[0])?;
-        let right = PacketInfo::parse(pair[1])?;
+    fn parse(input: &str) -> Result<Vec<(PacketInfo, PacketInfo)>, BoxedError> {
+        let mut pairs = Vec::new();
 
-        packets.push(left.clone());
-        packets.push(right.clone());
+        for pair in input.trim().split("\n\n") {
+            check_cancelled()?;
+            let pair: Vec<&str> = pair.trim().lines().collect();
 
-        if let Some(Ordering::Less) = left.partial_cmp(&right) {
-            correct_order += i as u32 + 1;
+            if pair.len() != 2 {
+                return_err!("Invalid pair of packets \"{:?}\"", pair);
+            }
+
+            pairs.push((
+                PacketInfo::parse(pair[0]).at_offset(offset_in(input, pair[0]))?,
+                PacketInfo::parse(pair[1]).at_offset(offset_in(input, pair[1]))?,
+            ));
         }
-    }
 
-    packets.push(PacketInfo::parse("[[2]]")?);
-    packets.push(PacketInfo::parse("[[6]]")?);
+        Ok(pairs)
+    }
 
-    packets.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    fn part_1(pairs: &Vec<(PacketInfo, PacketInfo)>) -> Result<u32, BoxedError> {
+        let mut correct_order: u32 = 0;
 
-    let mut decoder_key = packets
-        .iter()
-        .position(|packet| packet.input_str == "[[2]]")
-        .unwrap()
-        + 1;
+        for (i, (left, right)) in pairs.iter().enumerate() {
+            check_cancelled()?;
+            if let Some(Ordering::Less) = left.partial_cmp(right) {
+                correct_order += i as u32 + 1;
+            }
+        }
 
-    decoder_key *= packets
-        .iter()
-        .position(|packet| packet.input_str == "[[6]]")
-        .unwrap()
-        + 1;
+        Ok(correct_order)
+    }
 
-    Ok((correct_order.to_string(), decoder_key.to_string()))
-}
+    fn part_2(pairs: &Vec<(PacketInfo, PacketInfo)>) -> Result<usize, BoxedError> {
+        let mut packets = Vec::new();
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn part1_example() {
-        let input = r#"[1,1,3,1,1]
-[1,1,5,1,1]
+        for (left, right) in pairs {
+            packets.push(left.clone());
+            packets.push(right.clone());
+        }
 
-[[1],[2,3,4]]
-[[1],4]
+        packets.push(PacketInfo::parse("[[2]]")?);
+        packets.push(PacketInfo::parse("[[6]]")?);
 
-[9]
-[[8,7,6]]
+        packets.sort_by(|a, b| a.partial_cmp(b).unwrap());
 
-[[4,4],4,4]
-[[4,4],4,4,4]
+        let mut decoder_key = packets
+            .iter()
+            .position(|packet| packet.input_str == "[[2]]")
+            .unwrap()
+            + 1;
 
-[7,7,7,7]
-[7,7,7]
+        decoder_key *= packets
+            .iter()
+            .position(|packet| packet.input_str == "[[6]]")
+            .unwrap()
+            + 1;
 
-[]
-[3]
+        Ok(decoder_key)
+    }
+}
 
-[[[]]]
-[[]]
+register_day!(2022, 13, "Distress Signal", Day13);
 
-[1,[2,[3,[4,[5,6,7]]]],8,9]
-[1,[2,[3,[4,[5,6,0]]]],8,9]"#;
+#[cfg(test)]
+mod tests {
+    use super::Day13;
+    use crate::macros::example;
+    use crate::Solution;
 
-        let result = super::execute(input).unwrap().0;
+    #[test]
+    fn part1_example() {
+        let result = Day13::execute(example!(2022, 13)).unwrap().part_1.unwrap();
         assert_eq!("13", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"[1,1,3,1,1]
-[1,1,5,1,1]
-
-[[1],[2,3,4]]
-[[1],4]
-
-[9]
-[[8,7,6]]
-
-[[4,4],4,4]
-[[4,4],4,4,4]
-
-[7,7,7,7]
-[7,7,7]
-
-[]
-[3]
-
-[[[]]]
-[[]]
-
-[1,[2,[3,[4,[5,6,7]]]],8,9]
-[1,[2,[3,[4,[5,6,0]]]],8,9]"#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = Day13::execute(example!(2022, 13)).unwrap().part_2.unwrap();
         assert_eq!("140", result);
     }
 }
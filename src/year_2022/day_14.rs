@@ -1,6 +1,8 @@
+use crate::error::{offset_in, Locate};
+use crate::isolate::check_cancelled;
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
 use std::collections::HashMap;
 
@@ -98,7 +100,7 @@ impl Line {
         let mut points = Vec::new();
 
         for point in line.trim().split(" -> ") {
-            points.push(Point::parse(point)?);
+            points.push(Point::parse(point).at_offset(offset_in(line, point))?);
         }
 
         Line::new(points)
@@ -138,12 +140,14 @@ impl std::fmt::Display for Line {
     }
 }
 
+#[derive(Clone)]
 enum Tile {
     Rock,
     Sand,
 }
 
-struct Grid {
+#[derive(Clone)]
+pub struct Grid {
     points: HashMap<Point, Tile>,
     resting: usize,
     min: Point,
@@ -192,8 +196,8 @@ impl Grid {
     fn parse(input: &str) -> Result<Self, BoxedError> {
         let mut points = Vec::new();
 
-        for line in input.trim().lines() {
-            let line = Line::parse(line)?;
+        for (index, line) in input.trim().lines().enumerate() {
+            let line = Line::parse(line).at_line(index + 1)?;
             points.extend(line);
         }
 
@@ -270,42 +274,61 @@ impl std::fmt::Display for Grid {
     }
 }
 
-pub fn execute(input: &str) -> DayReturnType {
-    let mut grid = Grid::parse(input)?;
-    let mut hit_floor_at = 0;
+pub struct Day14;
 
-    loop {
-        let (hit_floor, finished) = grid.add_sand();
+impl Solution for Day14 {
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-        if hit_floor && hit_floor_at == 0 {
-            hit_floor_at = grid.resting - 1;
-        }
+    fn parse(input: &str) -> Result<Grid, BoxedError> {
+        Grid::parse(input)
+    }
 
-        if finished {
-            break;
+    fn part_1(grid: &Grid) -> Result<usize, BoxedError> {
+        let mut grid = grid.clone();
+
+        loop {
+            check_cancelled()?;
+            let (hit_floor, _) = grid.add_sand();
+
+            if hit_floor {
+                return Ok(grid.resting - 1);
+            }
         }
     }
 
-    Ok((hit_floor_at.to_string(), grid.resting.to_string()))
+    fn part_2(grid: &Grid) -> Result<usize, BoxedError> {
+        let mut grid = grid.clone();
+
+        loop {
+            check_cancelled()?;
+            let (_, finished) = grid.add_sand();
+
+            if finished {
+                return Ok(grid.resting);
+            }
+        }
+    }
 }
 
+register_day!(2022, 14, "Regolith Reservoir", Day14);
+
 #[cfg(test)]
 mod tests {
+    use super::Day14;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"498,4 -> 498,6 -> 496,6
-503,4 -> 502,4 -> 502,9 -> 494,9"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day14::execute(example!(2022, 14)).unwrap().part_1.unwrap();
         assert_eq!("24", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"498,4 -> 498,6 -> 496,6
-503,4 -> 502,4 -> 502,9 -> 494,9"#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = Day14::execute(example!(2022, 14)).unwrap().part_2.unwrap();
         assert_eq!("93", result);
     }
 }
@@ -1,6 +1,7 @@
+use crate::error::Locate;
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
 #[derive(PartialEq)]
 enum PlayableItems {
@@ -81,56 +82,82 @@ impl PlayableItems {
 
         item_score + round_score
     }
+}
+
+pub struct Round {
+    other: PlayableItems,
+    this: PlayableItems,
+    state: RoundStates,
+}
 
-    fn get_round_from_str(input: &str) -> Result<(u32, u32), BoxedError> {
+impl Round {
+    fn parse(input: &str) -> Result<Self, BoxedError> {
         let (other_str, this_str) = input.trim().split_at(1);
 
-        let other = PlayableItems::from_letter(other_str)?;
-        let round_state = RoundStates::from_letter(this_str)?;
+        Ok(Self {
+            other: PlayableItems::from_letter(other_str)?,
+            this: PlayableItems::from_letter(this_str)?,
+            state: RoundStates::from_letter(this_str)?,
+        })
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Round>;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-        let part_1 = PlayableItems::from_letter(this_str)?;
-        let part_2 = PlayableItems::from_round_state(&other, &round_state);
+    fn parse(input: &str) -> Result<Vec<Round>, BoxedError> {
+        let mut rounds = Vec::new();
 
-        Ok((
-            part_1.get_round_score(&other),
-            part_2.get_round_score(&other),
-        ))
+        for (index, line) in input.trim().lines().enumerate() {
+            rounds.push(Round::parse(line).at_line(index + 1)?);
+        }
+
+        Ok(rounds)
     }
-}
 
-pub fn execute(input: &str) -> DayReturnType {
-    let mut part1_total = 0;
-    let mut part2_total = 0;
+    fn part_1(rounds: &Vec<Round>) -> Result<u32, BoxedError> {
+        let mut total = 0;
 
-    for line in input.trim().lines() {
-        let (part1_score, part2_score) = unwrap_or_return!(PlayableItems::get_round_from_str(line));
+        for round in rounds {
+            total += round.this.get_round_score(&round.other);
+        }
 
-        part1_total += part1_score;
-        part2_total += part2_score;
+        Ok(total)
     }
 
-    Ok((part1_total.to_string(), part2_total.to_string()))
+    fn part_2(rounds: &Vec<Round>) -> Result<u32, BoxedError> {
+        let mut total = 0;
+
+        for round in rounds {
+            let this = PlayableItems::from_round_state(&round.other, &round.state);
+            total += this.get_round_score(&round.other);
+        }
+
+        Ok(total)
+    }
 }
 
+register_day!(2022, 2, "Rock Paper Scissors", Day2);
+
 #[cfg(test)]
 mod tests {
+    use super::Day2;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"A Y
-B X
-C Z"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day2::execute(example!(2022, 2)).unwrap().part_1.unwrap();
         assert_eq!("15", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"A Y
-B X
-C Z"#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = Day2::execute(example!(2022, 2)).unwrap().part_2.unwrap();
         assert_eq!("12", result);
     }
 }
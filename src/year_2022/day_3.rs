@@ -2,7 +2,7 @@ use itertools::Itertools;
 
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
 fn get_common_char(items: &[&str]) -> Option<char> {
     items[0].chars().find(|&letter| {
@@ -52,47 +52,71 @@ fn get_group_priority(group: &[&str]) -> Result<u32, BoxedError> {
     Ok(get_char_priority(&common_char))
 }
 
-pub fn execute(input: &str) -> DayReturnType {
-    let mut total_priority = 0;
-    let mut group_priority = 0;
-    let input = input.trim();
+pub struct Day3;
 
-    for line in input.lines() {
-        total_priority += unwrap_or_return!(get_line_priority(line))
+impl Solution for Day3 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>, BoxedError> {
+        let mut rucksacks = Vec::new();
+
+        for (index, line) in input.trim().lines().enumerate() {
+            let line = line.trim();
+
+            if let Some((column, item)) = line
+                .chars()
+                .enumerate()
+                .find(|(_, item)| !item.is_ascii_alphabetic())
+            {
+                return_err!(parse(index + 1, column + 1): "Invalid item \'{}\' in the line \"{}\"", item, line);
+            }
+
+            rucksacks.push(line.to_string());
+        }
+
+        Ok(rucksacks)
     }
 
-    for group in input.lines().chunks(3).into_iter() {
-        group_priority += unwrap_or_return!(get_group_priority(&group.collect_vec()))
+    fn part_1(rucksacks: &Vec<String>) -> Result<u32, BoxedError> {
+        let mut total_priority = 0;
+
+        for line in rucksacks {
+            total_priority += unwrap_or_return!(get_line_priority(line))
+        }
+
+        Ok(total_priority)
     }
 
-    Ok((total_priority.to_string(), group_priority.to_string()))
+    fn part_2(rucksacks: &Vec<String>) -> Result<u32, BoxedError> {
+        let mut group_priority = 0;
+
+        for group in rucksacks.iter().map(String::as_str).chunks(3).into_iter() {
+            group_priority += unwrap_or_return!(get_group_priority(&group.collect_vec()))
+        }
+
+        Ok(group_priority)
+    }
 }
 
+register_day!(2022, 3, "Rucksack Reorganization", Day3);
+
 #[cfg(test)]
 mod tests {
+    use super::Day3;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day3::execute(example!(2022, 3)).unwrap().part_1.unwrap();
         assert_eq!("157", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw"#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = Day3::execute(example!(2022, 3)).unwrap().part_2.unwrap();
         assert_eq!("70", result);
     }
 }
@@ -1,8 +1,9 @@
 use std::ops::RangeInclusive;
 
+use crate::error::Locate;
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
 fn str_to_range(input: &str) -> Result<RangeInclusive<u32>, BoxedError> {
     let split = input.split('-').collect::<Vec<&str>>();
@@ -29,7 +30,7 @@ fn str_to_range(input: &str) -> Result<RangeInclusive<u32>, BoxedError> {
     Ok(RangeInclusive::new(start, end))
 }
 
-fn line_to_ranges(line: &str) -> Result<(RangeInclusive<u32>, RangeInclusive<u32>), BoxedError> {
+fn line_to_ranges(line: &str) -> Result<Pair, BoxedError> {
     let split = line.trim().split(',').collect::<Vec<&str>>();
 
     if split.len() != 2 {
@@ -45,72 +46,84 @@ fn line_to_ranges(line: &str) -> Result<(RangeInclusive<u32>, RangeInclusive<u32
     Ok((first, second))
 }
 
-fn line_to_ordered_vecs(line: &str) -> Result<(Vec<u32>, Vec<u32>), BoxedError> {
-    let (first_range, second_range) = line_to_ranges(line)?;
-    let first = first_range.collect::<Vec<u32>>();
-    let second = second_range.collect::<Vec<u32>>();
+type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+fn pair_to_ordered_vecs(pair: &Pair) -> (Vec<u32>, Vec<u32>) {
+    let first = pair.0.clone().collect::<Vec<u32>>();
+    let second = pair.1.clone().collect::<Vec<u32>>();
 
     if first.len() > second.len() {
-        Ok((first, second))
+        (first, second)
     } else {
-        Ok((second, first))
+        (second, first)
     }
 }
 
-fn all_simular_in_line(line: &str) -> Result<bool, BoxedError> {
-    let (larger, smaller) = line_to_ordered_vecs(line)?;
+fn all_simular_in_pair(pair: &Pair) -> bool {
+    let (larger, smaller) = pair_to_ordered_vecs(pair);
 
-    Ok(smaller.iter().all(|num| larger.contains(num)))
+    smaller.iter().all(|num| larger.contains(num))
 }
 
-fn any_simular_in_line(line: &str) -> Result<bool, BoxedError> {
-    let (larger, smaller) = line_to_ordered_vecs(line)?;
+fn any_simular_in_pair(pair: &Pair) -> bool {
+    let (larger, smaller) = pair_to_ordered_vecs(pair);
 
-    Ok(smaller.iter().any(|num| larger.contains(num)))
+    smaller.iter().any(|num| larger.contains(num))
 }
 
-pub fn execute(input: &str) -> DayReturnType {
-    let mut all_overlaps = 0;
-    let mut any_overlaps = 0;
+pub struct Day4;
 
-    for line in input.trim().lines() {
-        if unwrap_or_return!(all_simular_in_line(line)) {
-            all_overlaps += 1;
-        }
+impl Solution for Day4 {
+    type Input = Vec<Pair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Pair>, BoxedError> {
+        let mut pairs = Vec::new();
 
-        if unwrap_or_return!(any_simular_in_line(line)) {
-            any_overlaps += 1;
+        for (index, line) in input.trim().lines().enumerate() {
+            pairs.push(line_to_ranges(line).at_line(index + 1)?);
         }
+
+        Ok(pairs)
     }
 
-    Ok((all_overlaps.to_string(), any_overlaps.to_string()))
+    fn part_1(pairs: &Vec<Pair>) -> Result<usize, BoxedError> {
+        let all_overlaps = pairs
+            .iter()
+            .filter(|pair| all_simular_in_pair(pair))
+            .count();
+
+        Ok(all_overlaps)
+    }
+
+    fn part_2(pairs: &Vec<Pair>) -> Result<usize, BoxedError> {
+        let any_overlaps = pairs
+            .iter()
+            .filter(|pair| any_simular_in_pair(pair))
+            .count();
+
+        Ok(any_overlaps)
+    }
 }
 
+register_day!(2022, 4, "Camp Cleanup", Day4);
+
 #[cfg(test)]
 mod tests {
+    use super::Day4;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day4::execute(example!(2022, 4)).unwrap().part_1.unwrap();
         assert_eq!("2", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8"#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = Day4::execute(example!(2022, 4)).unwrap().part_2.unwrap();
         assert_eq!("4", result);
     }
 }
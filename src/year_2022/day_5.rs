@@ -1,10 +1,13 @@
+use crate::error::{offset_in, Locate};
+use crate::isolate::check_cancelled;
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
+#[derive(Clone)]
 struct Crates {
     columns: Vec<Vec<char>>,
 }
@@ -45,7 +48,8 @@ impl MoveInstruction {
         let mut instructions = Vec::new();
 
         for instruction in lines.trim().lines() {
-            instructions.push(MoveInstruction::new(instruction)?);
+            instructions
+                .push(MoveInstruction::new(instruction).at_offset(offset_in(lines, instruction))?);
         }
 
         Ok(instructions)
@@ -178,57 +182,85 @@ impl std::fmt::Display for Crates {
     }
 }
 
-pub fn execute(input: &str) -> DayReturnType {
-    let (inital_setup, move_instructions) = unwrap_option_or_return!(
-        input.split_once("move"),
-        "Invalid Input: No split between the inital crate setup and the instructions!"
-    );
-    let move_instructions = "move".to_string() + move_instructions;
+pub struct Procedure {
+    crates: Crates,
+    instructions: Vec<MoveInstruction>,
+}
+
+impl Procedure {
+    fn get_answer(&self, at_once: bool) -> Result<String, BoxedError> {
+        let mut crates = self.crates.clone();
+
+        for instruction in &self.instructions {
+            check_cancelled()?;
+            crates.move_crates(instruction, at_once)?;
+        }
 
-    let mut crates_part1 = Crates::new(inital_setup)?;
-    let mut crates_part2 = Crates::new(inital_setup)?;
+        Ok(crates.get_answer())
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    // The crates are read by their column, so the spaces in the drawing have to be left alone
+    const TRIM_WHITESPACE: bool = false;
+
+    fn parse(input: &str) -> Result<Procedure, BoxedError> {
+        let instructions_start = unwrap_option_or_return!(
+            input.find("move"),
+            "Invalid Input: No split between the inital crate setup and the instructions!"
+        );
+        let (inital_setup, move_instructions) = input.split_at(instructions_start);
 
-    let instructions = MoveInstruction::parse_many(&move_instructions)?;
+        Ok(Procedure {
+            crates: Crates::new(inital_setup)?,
+            instructions: MoveInstruction::parse_many(move_instructions)
+                .at_offset(instructions_start)?,
+        })
+    }
 
-    for instruction in instructions {
-        crates_part1.move_crates(&instruction, false)?;
-        crates_part2.move_crates(&instruction, true)?;
+    fn part_1(procedure: &Procedure) -> Result<String, BoxedError> {
+        procedure.get_answer(false)
     }
 
-    Ok((crates_part1.get_answer(), crates_part2.get_answer()))
+    fn part_2(procedure: &Procedure) -> Result<String, BoxedError> {
+        procedure.get_answer(true)
+    }
 }
 
+register_day!(2022, 5, "Supply Stacks", Day5);
+
 #[cfg(test)]
 mod tests {
+    use super::Day5;
+    use crate::macros::example;
+    use crate::normalize::normalize;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"    [D]
-[N] [C]
-[Z] [M] [P]
- 1   2   3
-
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day5::execute(example!(2022, 5)).unwrap().part_1.unwrap();
         assert_eq!("CMZ", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"    [D]
-[N] [C]
-[Z] [M] [P]
- 1   2   3
+        let result = Day5::execute(example!(2022, 5)).unwrap().part_2.unwrap();
+        assert_eq!("MCD", result);
+    }
 
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2"#;
+    #[test]
+    fn windows_input() {
+        let input = format!("\u{feff}{}", example!(2022, 5).replace('\n', "\r\n"));
+        let input = normalize(&input, Day5::TRIM_WHITESPACE).input;
 
-        let result = super::execute(input).unwrap().1;
-        assert_eq!("MCD", result);
+        let answers = Day5::execute(&input).unwrap();
+        assert_eq!(Ok("CMZ".to_string()), answers.part_1);
+        assert_eq!(Ok("MCD".to_string()), answers.part_2);
     }
 }
@@ -1,12 +1,34 @@
 use crate::macros::*;
-use crate::DayReturnType;
+use crate::BoxedError;
+use crate::Solution;
 
-pub fn execute(_input: &str) -> DayReturnType {
-    return_err!("Code For This Day Is Not Complete!");
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = ();
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(_input: &str) -> Result<(), BoxedError> {
+        Ok(())
+    }
+
+    fn part_1(_input: &()) -> Result<String, BoxedError> {
+        return_err!(not_implemented);
+    }
+
+    fn part_2(_input: &()) -> Result<String, BoxedError> {
+        return_err!(not_implemented);
+    }
 }
 
+register_day!(2022, 6, "Tuning Trouble", Day6);
+
 // #[cfg(test)]
 // mod tests {
+//     use super::Day6;
+//     use crate::Solution;
+//
 //     #[test]
 //     fn part1_example() {
 //         let test_data = vec![
@@ -18,7 +40,7 @@ pub fn execute(_input: &str) -> DayReturnType {
 //         ];
 
 //         for (input, answer) in test_data {
-//             let result = super::execute(input).unwrap().0;
+//             let result = Day6::execute(input).unwrap().part_1.unwrap();
 //             assert_eq!(answer, result);
 //         }
 //     }
@@ -34,7 +56,7 @@ pub fn execute(_input: &str) -> DayReturnType {
 //         ];
 
 //         for (input, answer) in test_data {
-//             let result = super::execute(input).unwrap().1;
+//             let result = Day6::execute(input).unwrap().part_2.unwrap();
 //             assert_eq!(answer, result);
 //         }
 //     }
@@ -1,12 +1,34 @@
 use crate::macros::*;
-use crate::DayReturnType;
+use crate::BoxedError;
+use crate::Solution;
 
-pub fn execute(_input: &str) -> DayReturnType {
-    return_err!("Code For This Day Is Not Complete!");
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = ();
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(_input: &str) -> Result<(), BoxedError> {
+        Ok(())
+    }
+
+    fn part_1(_input: &()) -> Result<String, BoxedError> {
+        return_err!(not_implemented);
+    }
+
+    fn part_2(_input: &()) -> Result<String, BoxedError> {
+        return_err!(not_implemented);
+    }
 }
 
+register_day!(2022, 7, "No Space Left On Device", Day7);
+
 // #[cfg(test)]
 // mod tests {
+//     use super::Day7;
+//     use crate::Solution;
+//
 //     #[test]
 //     fn part1_example() {
 //         let input = r#"$ cd /
@@ -33,7 +55,7 @@ pub fn execute(_input: &str) -> DayReturnType {
 // 5626152 d.ext
 // 7214296 k"#;
 
-//         let result = super::execute(input).unwrap().0;
+//         let result = Day7::execute(input).unwrap().part_1.unwrap();
 //         assert_eq!("95437", result);
 //     }
 
@@ -63,7 +85,7 @@ pub fn execute(_input: &str) -> DayReturnType {
 // 5626152 d.ext
 // 7214296 k"#;
 
-//         let result = super::execute(input).unwrap().1;
+//         let result = Day7::execute(input).unwrap().part_2.unwrap();
 //         assert_eq!("24933642", result);
 //     }
 // }
@@ -1,10 +1,11 @@
 use std::ops::Range;
 
+use crate::isolate::check_cancelled;
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
-struct Trees {
+pub struct Trees {
     data: Vec<Vec<u32>>,
     width: usize,
     height: usize,
@@ -105,11 +106,13 @@ impl Trees {
         (scenic_score, visable_from_outside)
     }
 
-    fn get_heightest_scenic_score_and_count_visable_trees(&self) -> (u32, u32) {
+    fn get_heightest_scenic_score_and_count_visable_trees(&self) -> Result<(u32, u32), BoxedError> {
         let mut visable_count = 0;
         let mut highest_scenic_score = 0;
 
         for x in 0..self.width {
+            check_cancelled()?;
+
             for y in 0..self.height {
                 let (scenic_score, is_visable) = self.get_scenic_score_and_outside_visability(x, y);
 
@@ -123,48 +126,58 @@ impl Trees {
             }
         }
 
-        (visable_count, highest_scenic_score)
+        Ok((visable_count, highest_scenic_score))
     }
 }
 
-pub fn execute(input: &str) -> DayReturnType {
-    let lines: Vec<&str> = input.lines().collect();
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Trees;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Trees, BoxedError> {
+        let lines: Vec<&str> = input.lines().collect();
 
-    if lines.is_empty() {
-        return_err!("Input must have at least one line!");
+        if lines.is_empty() {
+            return_err!("Input must have at least one line!");
+        }
+
+        Ok(unwrap_or_return!(Trees::new(lines)))
     }
 
-    let trees = unwrap_or_return!(Trees::new(lines));
+    fn part_1(trees: &Trees) -> Result<u32, BoxedError> {
+        let (visable_count, _) = trees.get_heightest_scenic_score_and_count_visable_trees()?;
+
+        Ok(visable_count)
+    }
 
-    let (visable_count, highest_scenic_score) =
-        trees.get_heightest_scenic_score_and_count_visable_trees();
+    fn part_2(trees: &Trees) -> Result<u32, BoxedError> {
+        let (_, highest_scenic_score) =
+            trees.get_heightest_scenic_score_and_count_visable_trees()?;
 
-    Ok((visable_count.to_string(), highest_scenic_score.to_string()))
+        Ok(highest_scenic_score)
+    }
 }
 
+register_day!(2022, 8, "Treetop Tree House", Day8);
+
 #[cfg(test)]
 mod tests {
+    use super::Day8;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"30373
-25512
-65332
-33549
-35390"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day8::execute(example!(2022, 8)).unwrap().part_1.unwrap();
         assert_eq!("21", result);
     }
 
     #[test]
     fn part2_example() {
-        let input = r#"30373
-25512
-65332
-33549
-35390"#;
-
-        let result = super::execute(input).unwrap().1;
+        let result = Day8::execute(example!(2022, 8)).unwrap().part_2.unwrap();
         assert_eq!("8", result);
     }
 }
@@ -1,8 +1,10 @@
+use crate::error::{offset_in, Locate};
+use crate::isolate::check_cancelled;
 use crate::macros::*;
 use crate::BoxedError;
-use crate::DayReturnType;
+use crate::Solution;
 
-enum Direction {
+pub enum Direction {
     Up,
     Down,
     Left,
@@ -136,96 +138,99 @@ impl Rope {
         self.move_part(i + 1, delta.signum());
     }
 
-    fn move_head(&mut self, direction: Direction, step_size: i32) {
-        let step = Pos::direction(&direction);
+    fn move_head(&mut self, direction: &Direction, step_size: i32) {
+        let step = Pos::direction(direction);
 
         for _step_index in 0..step_size {
             self.move_part(0, step);
         }
     }
 
-    fn move_using_str(&mut self, line: &str) -> Result<(), BoxedError> {
-        let (lhs, rhs) = line.trim().split_at(1);
+    fn move_many(&mut self, moves: &[Move]) -> Result<(), BoxedError> {
+        for (direction, step_size) in moves {
+            check_cancelled()?;
+            self.move_head(direction, *step_size);
+        }
 
-        let direction = match lhs.to_uppercase().trim() {
-            "U" => Direction::Up,
-            "D" => Direction::Down,
-            "L" => Direction::Left,
-            "R" => Direction::Right,
-            _ => return_err!("Invalid Direction \"{}\"", lhs.trim()),
-        };
+        Ok(())
+    }
+}
 
-        let step_size: i32 =
-            unwrap_or_return!(rhs.trim().parse(), "Invalid Step Size \"{}\"", rhs.trim());
+type Move = (Direction, i32);
 
-        self.move_head(direction, step_size);
+fn parse_move(line: &str) -> Result<Move, BoxedError> {
+    let line = line.trim();
+    let (lhs, rhs) = line.split_at(1);
 
-        Ok(())
-    }
+    let direction = match lhs.to_uppercase().trim() {
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        _ => return_err!(parse(offset: 0): "Invalid Direction \"{}\"", lhs.trim()),
+    };
+
+    let step_size: i32 = unwrap_or_return!(
+        rhs.trim().parse(),
+        parse(offset: offset_in(line, rhs.trim())): "Invalid Step Size \"{}\"",
+        rhs.trim()
+    );
+
+    Ok((direction, step_size))
 }
 
-pub fn execute(input: &str) -> DayReturnType {
-    let mut rope_part_1 = unwrap_or_return!(Rope::new(2));
-    let mut rope_part_2 = unwrap_or_return!(Rope::new(10));
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Move>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    for line in input.trim().lines() {
-        rope_part_1.move_using_str(line.trim())?;
-        rope_part_2.move_using_str(line.trim())?;
+    fn parse(input: &str) -> Result<Vec<Move>, BoxedError> {
+        let mut moves = Vec::new();
+
+        for (index, line) in input.trim().lines().enumerate() {
+            moves.push(parse_move(line.trim()).at_line(index + 1)?);
+        }
+
+        Ok(moves)
     }
 
-    Ok((
-        rope_part_1.unique_tail_positions.len().to_string(),
-        rope_part_2.unique_tail_positions.len().to_string(),
-    ))
+    fn part_1(moves: &Vec<Move>) -> Result<usize, BoxedError> {
+        let mut rope = unwrap_or_return!(Rope::new(2));
+        rope.move_many(moves)?;
+
+        Ok(rope.unique_tail_positions.len())
+    }
+
+    fn part_2(moves: &Vec<Move>) -> Result<usize, BoxedError> {
+        let mut rope = unwrap_or_return!(Rope::new(10));
+        rope.move_many(moves)?;
+
+        Ok(rope.unique_tail_positions.len())
+    }
 }
 
+register_day!(2022, 9, "Rope Bridge", Day9);
+
 #[cfg(test)]
 mod tests {
+    use super::Day9;
+    use crate::macros::example;
+    use crate::Solution;
+
     #[test]
     fn part1_example() {
-        let input = r#"R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2"#;
-
-        let result = super::execute(input).unwrap().0;
+        let result = Day9::execute(example!(2022, 9)).unwrap().part_1.unwrap();
         assert_eq!("13", result);
     }
 
     #[test]
     fn part2_example() {
-        let test_data = vec![
-            (
-                r#"R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2"#,
-                "1",
-            ),
-            (
-                r#"R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20"#,
-                "36",
-            ),
-        ];
-
-        for (input, answer) in test_data {
-            let result = super::execute(input).unwrap().1;
-            assert_eq!(answer, result);
-        }
+        let result = Day9::execute(example!(2022, 9)).unwrap().part_2.unwrap();
+        assert_eq!("1", result);
+
+        let result = Day9::execute(example!(2022, 9, 2)).unwrap().part_2.unwrap();
+        assert_eq!("36", result);
     }
 }